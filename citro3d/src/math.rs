@@ -1,24 +1,34 @@
 //! Safe wrappers for working with matrix and vector types provided by `citro3d`.
 
 // TODO: bench FFI calls into `inline statics` generated by bindgen, vs
-// reimplementing some of those calls. Many of them are pretty trivial impls
+// reimplementing some of those calls. Many of them are pretty trivial impls,
+// and the most trivial ones are already reimplemented in `ops`.
+//
+// Reimplementing them all in Rust would also let these tests run on the host
+// instead of only on a 3DS or emulator. That has to start in `citro3d-sys`
+// though: its build script requires devkitARM and links `libctru`, so nothing
+// here builds for the host until it can provide the `C3D_*` types (with the
+// same WZYX layout) and fallbacks for the `FVec*_`, `Mtx_*` and `Quat_*`
+// statics on other targets.
 
+mod fquat;
 mod fvec;
+#[cfg(feature = "glam")]
+mod glam;
+mod ivec;
 mod matrix;
+#[cfg(feature = "mint")]
+mod mint;
 mod ops;
 mod projection;
+#[cfg(feature = "serde")]
+mod serde;
 
+pub use fquat::FQuat;
 pub use fvec::{FVec, FVec3, FVec4};
-pub use matrix::{Matrix, Matrix3, Matrix4};
+pub use ivec::IVec;
+pub use matrix::{Matrix, Matrix3, Matrix4, TransformOrder};
 pub use projection::{
     AspectRatio, ClipPlanes, CoordinateOrientation, Orthographic, Perspective, Projection,
     ScreenOrientation, StereoDisplacement,
 };
-
-/// A 4-vector of `u8`s.
-#[doc(alias = "C3D_IVec")]
-pub struct IVec(citro3d_sys::C3D_IVec);
-
-/// A quaternion, internally represented the same way as [`FVec`].
-#[doc(alias = "C3D_FQuat")]
-pub struct FQuat(citro3d_sys::C3D_FQuat);
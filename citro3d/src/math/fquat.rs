@@ -0,0 +1,868 @@
+//! Floating-point quaternions.
+
+use std::f32::consts::PI;
+use std::fmt;
+
+use super::{FVec3, Matrix4, TransformOrder};
+
+/// A quaternion, internally represented the same way as [`FVec`](super::FVec).
+#[derive(Clone, Copy)]
+#[doc(alias = "C3D_FQuat")]
+pub struct FQuat(pub(crate) citro3d_sys::C3D_FQuat);
+
+impl fmt::Debug for FQuat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FQuat")
+            .field(&self.i())
+            .field(&self.j())
+            .field(&self.k())
+            .field(&self.r())
+            .finish()
+    }
+}
+
+impl Default for FQuat {
+    /// The identity quaternion. See [`FQuat::identity`].
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl FQuat {
+    /// Create a new [`FQuat`] from its `ijk` (imaginary) and `r` (real) components.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FQuat;
+    /// let q = FQuat::new(0.0, 0.0, 0.0, 1.0);
+    /// assert_eq!(q, FQuat::identity());
+    /// ```
+    #[doc(alias = "Quat_New")]
+    pub fn new(i: f32, j: f32, k: f32, r: f32) -> Self {
+        Self(unsafe { citro3d_sys::FVec4_New(i, j, k, r) })
+    }
+
+    /// Construct the identity quaternion, which represents no rotation.
+    #[doc(alias = "Quat_Identity")]
+    pub fn identity() -> Self {
+        Self(unsafe { citro3d_sys::Quat_Identity() })
+    }
+
+    /// The quaternion's `i` component (also called `x`).
+    #[doc(alias = "x")]
+    pub fn i(self) -> f32 {
+        unsafe { self.0.__bindgen_anon_2.i }
+    }
+
+    /// The quaternion's `j` component (also called `y`).
+    #[doc(alias = "y")]
+    pub fn j(self) -> f32 {
+        unsafe { self.0.__bindgen_anon_2.j }
+    }
+
+    /// The quaternion's `k` component (also called `z`).
+    #[doc(alias = "z")]
+    pub fn k(self) -> f32 {
+        unsafe { self.0.__bindgen_anon_2.k }
+    }
+
+    /// The quaternion's real `r` component (also called `w`).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FQuat;
+    /// let q = FQuat::identity();
+    /// assert_eq!((q.i(), q.j(), q.k(), q.r()), (0.0, 0.0, 0.0, 1.0));
+    /// ```
+    #[doc(alias = "w")]
+    pub fn r(self) -> f32 {
+        unsafe { self.0.__bindgen_anon_2.r }
+    }
+
+    /// Construct a quaternion representing a rotation of `angle` radians around
+    /// the given `axis`.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FQuat, FVec3};
+    /// # use std::f32::consts::PI;
+    /// let q = FQuat::from_axis_angle(FVec3::new(0.0, 1.0, 0.0), PI / 2.0);
+    /// ```
+    #[doc(alias = "Quat_FromAxisAngle")]
+    pub fn from_axis_angle(axis: FVec3, angle: f32) -> Self {
+        Self(unsafe { citro3d_sys::Quat_FromAxisAngle(axis.0, angle) })
+    }
+
+    /// Construct a quaternion from Euler angles (in radians), rotating by
+    /// `pitch` around the X axis, then `yaw` around the Y axis, then `roll`
+    /// around the Z axis. With [`TransformOrder::World`], each rotation is
+    /// around the fixed world axes, and with [`TransformOrder::Local`] it is
+    /// around the axes as rotated by the previous ones.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FQuat, TransformOrder};
+    /// # use approx::assert_abs_diff_eq;
+    /// let q = FQuat::from_pitch_yaw_roll(0.1, 0.2, 0.3, TransformOrder::World);
+    /// assert_abs_diff_eq!(q, FQuat::identity().rotate_z(0.3).rotate_y(0.2).rotate_x(0.1));
+    /// ```
+    #[doc(alias = "Quat_FromPitchYawRoll")]
+    #[doc(alias = "from_euler")]
+    pub fn from_pitch_yaw_roll(pitch: f32, yaw: f32, roll: f32, order: TransformOrder) -> Self {
+        Self(unsafe { citro3d_sys::Quat_FromPitchYawRoll(pitch, yaw, roll, is_right_side(order)) })
+    }
+
+    /// Get the axis and angle (in radians) of the rotation this quaternion
+    /// represents. This is the inverse of [`from_axis_angle`](Self::from_axis_angle),
+    /// except that the angle is always in the range `0.0..=PI`, with the axis
+    /// flipped if necessary.
+    ///
+    /// The quaternion should be normalized. For (approximately) no rotation,
+    /// the axis is arbitrary, so [`FVec3::UNIT_X`] is returned with an angle
+    /// of zero.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FQuat, FVec3};
+    /// # use approx::assert_abs_diff_eq;
+    /// let q = FQuat::from_axis_angle(FVec3::new(0.0, 2.0, 0.0), 0.5);
+    /// let (axis, angle) = q.to_axis_angle();
+    /// assert_abs_diff_eq!(axis, FVec3::UNIT_Y);
+    /// assert_abs_diff_eq!(angle, 0.5);
+    /// ```
+    pub fn to_axis_angle(self) -> (FVec3, f32) {
+        // q and -q are the same rotation, so pick the one with the smaller angle
+        let q = if self.r() < 0.0 { -self } else { self };
+        let axis = FVec3::new(q.i(), q.j(), q.k());
+
+        let sin_half_angle = axis.magnitude();
+        if sin_half_angle <= f32::EPSILON {
+            return (FVec3::UNIT_X, 0.0);
+        }
+
+        // atan2 is more accurate than acos for angles near zero
+        let angle = 2.0 * sin_half_angle.atan2(q.r());
+        (axis / sin_half_angle, angle)
+    }
+
+    /// Construct the shortest rotation which rotates the direction of `from`
+    /// to the direction of `to`. Neither vector needs to be normalized, but
+    /// they must be non-zero.
+    ///
+    /// If the vectors point in opposite directions, there are infinitely many
+    /// shortest rotations, so a rotation of 180° around an arbitrary
+    /// perpendicular axis is used.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FQuat, FVec3};
+    /// # use approx::assert_abs_diff_eq;
+    /// # use std::f32::consts::FRAC_PI_2;
+    /// let q = FQuat::rotation_between(FVec3::UNIT_X, FVec3::new(0.0, 2.0, 0.0));
+    /// assert_abs_diff_eq!(q, FQuat::from_axis_angle(FVec3::UNIT_Z, FRAC_PI_2));
+    /// ```
+    pub fn rotation_between(from: FVec3, to: FVec3) -> Self {
+        let from = from.normalize();
+        let to = to.normalize();
+        let cos = from.dot(to);
+
+        if cos < -1.0 + f32::EPSILON.sqrt() {
+            // Antiparallel, so any axis perpendicular to `from` will do
+            let mut axis = from.cross(FVec3::UNIT_X);
+            if axis.magnitude_squared() < f32::EPSILON {
+                axis = from.cross(FVec3::UNIT_Y);
+            }
+            return Self::from_axis_angle(axis.normalize(), PI);
+        }
+
+        // |from × to| = sin(θ) and 1 + cos(θ) = 2cos²(θ/2), so this is just a
+        // scaled version of the usual half-angle form, without any trig.
+        let axis = from.cross(to);
+        Self::new(axis.x(), axis.y(), axis.z(), 1.0 + cos).normalize()
+    }
+
+    /// Construct the rotation which turns an object at `source`, facing in
+    /// the `forward` direction, to face `target` instead. This is the
+    /// quaternion counterpart to [`Matrix4::looking_at`].
+    ///
+    /// `up` is only used when `target` is directly behind the object, in
+    /// which case it is turned 180° around `up`. If `source` and `target` are
+    /// the same point, there is no direction to face, so the identity is
+    /// returned.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FQuat, FVec3};
+    /// # use approx::assert_abs_diff_eq;
+    /// let position = FVec3::new(1.0, 0.0, 0.0);
+    /// let target = FVec3::new(1.0, 5.0, 0.0);
+    /// let q = FQuat::look_at(position, target, FVec3::UNIT_Z, FVec3::UNIT_Y);
+    /// assert_abs_diff_eq!(q * FVec3::UNIT_Z, FVec3::UNIT_Y);
+    /// ```
+    #[doc(alias = "Quat_LookAt")]
+    pub fn look_at(source: FVec3, target: FVec3, forward: FVec3, up: FVec3) -> Self {
+        if source == target {
+            return Self::identity();
+        }
+
+        // Quat_LookAt compares `forward` with a normalized direction, so it
+        // must be normalized too
+        Self(unsafe { citro3d_sys::Quat_LookAt(source.0, target.0, forward.normalize().0, up.0) })
+    }
+
+    /// Construct a quaternion from a rotation matrix. Only the upper-left 3x3
+    /// part of the matrix is used, which should be a pure rotation (i.e.
+    /// without any scale). Use [`Matrix4::decompose`] to extract the rotation
+    /// from a more general transformation matrix.
+    ///
+    /// This is the inverse of [`to_matrix`](Self::to_matrix).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FQuat, Matrix4};
+    /// # use approx::assert_abs_diff_eq;
+    /// let mut mtx = Matrix4::identity();
+    /// mtx.rotate_y(0.5);
+    ///
+    /// let q = FQuat::from_matrix(&mtx);
+    /// assert_abs_diff_eq!(q, FQuat::identity().rotate_y(0.5));
+    /// ```
+    #[doc(alias = "Quat_FromMtx")]
+    pub fn from_matrix(matrix: &Matrix4) -> Self {
+        Self(unsafe { citro3d_sys::Quat_FromMtx(matrix.as_raw()) })
+    }
+
+    /// Convert the quaternion to an equivalent rotation matrix.
+    /// See [`Matrix4::from_quat`].
+    #[doc(alias = "Mtx_FromQuat")]
+    pub fn to_matrix(self) -> Matrix4 {
+        Matrix4::from_quat(self)
+    }
+
+    /// The dot product of two quaternions.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FQuat;
+    /// # use approx::assert_abs_diff_eq;
+    /// let l = FQuat::new(1.0, 2.0, 3.0, 4.0);
+    /// let r = FQuat::new(1.0, 0.5, 1.0, 0.5);
+    /// assert_abs_diff_eq!(l.dot(r), 7.0);
+    /// ```
+    #[doc(alias = "Quat_Dot")]
+    pub fn dot(self, rhs: Self) -> f32 {
+        unsafe { citro3d_sys::FVec4_Dot(self.0, rhs.0) }
+    }
+
+    /// The magnitude of the quaternion. Quaternions representing a rotation
+    /// should have a magnitude of `1.0`.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FQuat;
+    /// # use approx::assert_abs_diff_eq;
+    /// let q = FQuat::new(1.0, 1.0, 1.0, 1.0);
+    /// assert_abs_diff_eq!(q.magnitude(), 2.0);
+    /// ```
+    pub fn magnitude(self) -> f32 {
+        unsafe { citro3d_sys::FVec4_Magnitude(self.0) }
+    }
+
+    /// Normalize the quaternion to a magnitude of `1.0`. This is useful to
+    /// correct for floating-point drift after many multiplications.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FQuat;
+    /// # use approx::assert_abs_diff_eq;
+    /// let q = FQuat::new(1.0, 1.0, 1.0, 1.0);
+    /// assert_abs_diff_eq!(q.normalize(), FQuat::new(0.5, 0.5, 0.5, 0.5));
+    /// ```
+    #[doc(alias = "Quat_Normalize")]
+    pub fn normalize(self) -> Self {
+        Self(unsafe { citro3d_sys::FVec4_Normalize(self.0) })
+    }
+
+    /// The conjugate of the quaternion, i.e. with its imaginary components
+    /// negated. For a unit quaternion, this is the same as its
+    /// [`inverse`](Self::inverse), and represents the opposite rotation.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FQuat;
+    /// let q = FQuat::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(q.conjugate(), FQuat::new(-1.0, -2.0, -3.0, 4.0));
+    /// ```
+    #[doc(alias = "Quat_Conjugate")]
+    pub fn conjugate(self) -> Self {
+        Self(unsafe { citro3d_sys::Quat_Conjugate(self.0) })
+    }
+
+    /// The inverse of the quaternion, such that `q * q.inverse()` is the
+    /// identity, or `None` if the quaternion is zero.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FQuat;
+    /// # use approx::assert_abs_diff_eq;
+    /// let q = FQuat::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_abs_diff_eq!(q * q.inverse().unwrap(), FQuat::identity());
+    ///
+    /// assert_eq!(FQuat::new(0.0, 0.0, 0.0, 0.0).inverse(), None);
+    /// ```
+    #[doc(alias = "Quat_Inverse")]
+    pub fn inverse(self) -> Option<Self> {
+        // Quat_Inverse divides by the squared magnitude
+        if self.dot(self) == 0.0 {
+            None
+        } else {
+            Some(Self(unsafe { citro3d_sys::Quat_Inverse(self.0) }))
+        }
+    }
+
+    /// Normalized linear interpolation between two quaternions. This is cheaper
+    /// than [`slerp`](Self::slerp), but does not interpolate at a constant
+    /// angular velocity.
+    ///
+    /// `t` is not clamped: values outside `0.0..=1.0` extrapolate beyond `self`
+    /// and `other`. The interpolation always takes the shortest path between
+    /// the two rotations.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FQuat;
+    /// # use std::f32::consts::PI;
+    /// let start = FQuat::identity();
+    /// let end = FQuat::identity().rotate_z(PI / 2.0);
+    /// let halfway = start.lerp(end, 0.5);
+    /// ```
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let other = self.shortest_path_to(other);
+        unsafe {
+            let lerped = citro3d_sys::FVec4_Add(
+                citro3d_sys::FVec4_Scale(self.0, 1.0 - t),
+                citro3d_sys::FVec4_Scale(other.0, t),
+            );
+            Self(lerped).normalize()
+        }
+    }
+
+    /// Spherical linear interpolation between two quaternions, at a constant
+    /// angular velocity.
+    ///
+    /// `t` is not clamped: values outside `0.0..=1.0` extrapolate beyond `self`
+    /// and `other`. The interpolation always takes the shortest path between
+    /// the two rotations.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FQuat;
+    /// # use std::f32::consts::PI;
+    /// let start = FQuat::identity();
+    /// let end = FQuat::identity().rotate_z(PI / 2.0);
+    /// let halfway = start.slerp(end, 0.5);
+    /// ```
+    pub fn slerp(self, other: Self, t: f32) -> Self {
+        let other = self.shortest_path_to(other);
+        let cos_theta = self.dot(other);
+
+        // For nearly-identical rotations sin(theta) approaches zero, but
+        // linear interpolation is a good enough approximation anyway.
+        if cos_theta > 0.9995 {
+            return self.lerp(other, t);
+        }
+
+        let theta = cos_theta.acos();
+        let sin_theta = theta.sin();
+        let lhs = ((1.0 - t) * theta).sin() / sin_theta;
+        let rhs = (t * theta).sin() / sin_theta;
+
+        unsafe {
+            Self(citro3d_sys::FVec4_Add(
+                citro3d_sys::FVec4_Scale(self.0, lhs),
+                citro3d_sys::FVec4_Scale(other.0, rhs),
+            ))
+        }
+    }
+
+    /// `q` and `-q` represent the same rotation, so pick whichever one is
+    /// closer to `self` for interpolation.
+    fn shortest_path_to(self, other: Self) -> Self {
+        if self.dot(other) < 0.0 {
+            -other
+        } else {
+            other
+        }
+    }
+
+    // region: Quaternion rotations
+
+    /// Rotate the quaternion by the given angle (in radians) around the given axis.
+    /// The rotation is applied before the existing rotation, i.e. around the
+    /// axes as rotated by `self`; see [`TransformOrder::Local`].
+    #[doc(alias = "Quat_Rotate")]
+    pub fn rotate(self, axis: FVec3, angle: f32) -> Self {
+        self.rotate_with_order(axis, angle, TransformOrder::Local)
+    }
+
+    /// Rotate the quaternion by the given angle (in radians) around the given
+    /// axis, in the given [`TransformOrder`].
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use std::f32::consts::FRAC_PI_2;
+    /// # use citro3d::math::{FQuat, FVec3, TransformOrder};
+    /// # use approx::assert_abs_diff_eq;
+    /// let yawed = FQuat::from_axis_angle(FVec3::UNIT_Y, FRAC_PI_2);
+    ///
+    /// // Pitching around the world X axis leaves the yawed +Z (now +X) alone...
+    /// let world = yawed.rotate_with_order(FVec3::UNIT_X, FRAC_PI_2, TransformOrder::World);
+    /// assert_abs_diff_eq!(world * FVec3::UNIT_Z, FVec3::UNIT_X, epsilon = 1.0e-5);
+    ///
+    /// // ...or around the object's own X axis, which now points along -Z
+    /// let local = yawed.rotate_with_order(FVec3::UNIT_X, FRAC_PI_2, TransformOrder::Local);
+    /// assert_abs_diff_eq!(local * FVec3::UNIT_Z, -FVec3::UNIT_Y, epsilon = 1.0e-5);
+    /// ```
+    #[doc(alias = "Quat_Rotate")]
+    pub fn rotate_with_order(self, axis: FVec3, angle: f32, order: TransformOrder) -> Self {
+        Self(unsafe { citro3d_sys::Quat_Rotate(self.0, axis.0, angle, is_right_side(order)) })
+    }
+
+    /// Rotate the quaternion by the given angle (in radians) around the X axis,
+    /// in [`TransformOrder::Local`] space like [`rotate`](Self::rotate).
+    #[doc(alias = "Quat_RotateX")]
+    pub fn rotate_x(self, angle: f32) -> Self {
+        self.rotate_x_with_order(angle, TransformOrder::Local)
+    }
+
+    /// Rotate the quaternion by the given angle (in radians) around the X axis,
+    /// in the given [`TransformOrder`].
+    #[doc(alias = "Quat_RotateX")]
+    pub fn rotate_x_with_order(self, angle: f32, order: TransformOrder) -> Self {
+        Self(unsafe { citro3d_sys::Quat_RotateX(self.0, angle, is_right_side(order)) })
+    }
+
+    /// Rotate the quaternion by the given angle (in radians) around the Y axis,
+    /// in [`TransformOrder::Local`] space like [`rotate`](Self::rotate).
+    #[doc(alias = "Quat_RotateY")]
+    pub fn rotate_y(self, angle: f32) -> Self {
+        self.rotate_y_with_order(angle, TransformOrder::Local)
+    }
+
+    /// Rotate the quaternion by the given angle (in radians) around the Y axis,
+    /// in the given [`TransformOrder`].
+    #[doc(alias = "Quat_RotateY")]
+    pub fn rotate_y_with_order(self, angle: f32, order: TransformOrder) -> Self {
+        Self(unsafe { citro3d_sys::Quat_RotateY(self.0, angle, is_right_side(order)) })
+    }
+
+    /// Rotate the quaternion by the given angle (in radians) around the Z axis,
+    /// in [`TransformOrder::Local`] space like [`rotate`](Self::rotate).
+    #[doc(alias = "Quat_RotateZ")]
+    pub fn rotate_z(self, angle: f32) -> Self {
+        self.rotate_z_with_order(angle, TransformOrder::Local)
+    }
+
+    /// Rotate the quaternion by the given angle (in radians) around the Z axis,
+    /// in the given [`TransformOrder`].
+    #[doc(alias = "Quat_RotateZ")]
+    pub fn rotate_z_with_order(self, angle: f32, order: TransformOrder) -> Self {
+        Self(unsafe { citro3d_sys::Quat_RotateZ(self.0, angle, is_right_side(order)) })
+    }
+
+    // endregion
+}
+
+/// The `bRightSide` argument of the `Quat_*` functions for a given order.
+/// Unlike the `Mtx_*` functions, `bRightSide` multiplies the new rotation on
+/// the left of the quaternion, i.e. applies it in world space.
+fn is_right_side(order: TransformOrder) -> bool {
+    matches!(order, TransformOrder::World)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use approx::{assert_abs_diff_eq, assert_abs_diff_ne};
+
+    use super::*;
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", FQuat::new(1.0, 2.0, 3.0, 4.0)),
+            "FQuat(1.0, 2.0, 3.0, 4.0)"
+        );
+    }
+
+    #[test]
+    fn identity_to_matrix() {
+        assert_abs_diff_eq!(FQuat::identity().to_matrix(), Matrix4::identity());
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(FQuat::default(), FQuat::identity());
+    }
+
+    #[test]
+    fn components() {
+        let q = FQuat::identity();
+        assert_eq!((q.i(), q.j(), q.k(), q.r()), (0.0, 0.0, 0.0, 1.0));
+        assert_eq!(FQuat::new(q.i(), q.j(), q.k(), q.r()), q);
+
+        let q = FQuat::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!((q.i(), q.j(), q.k(), q.r()), (1.0, 2.0, 3.0, 4.0));
+        // Stored in RKJI order, like FVec
+        assert_eq!(unsafe { q.0.c }, [4.0, 3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn from_axis_angle() {
+        let axis = FVec3::new(1.0, -2.0, 3.0);
+        let angle = 0.7;
+
+        let mut expected = Matrix4::identity();
+        expected.rotate(axis, angle);
+
+        let actual = FQuat::from_axis_angle(axis, angle).to_matrix();
+        assert_abs_diff_eq!(actual, expected);
+    }
+
+    #[test]
+    fn from_axis_angle_matches_matrix() {
+        for angle in [0.0, 0.3, FRAC_PI_2, -2.0, PI] {
+            let q = FQuat::from_axis_angle(FVec3::UNIT_Z, angle);
+            assert_abs_diff_eq!(
+                Matrix4::from_quat(q),
+                Matrix4::from_rotation_z(angle),
+                epsilon = 1.0e-5
+            );
+        }
+    }
+
+    #[test]
+    fn from_pitch_yaw_roll() {
+        let (pitch, yaw, roll) = (0.4, -1.1, 2.5);
+
+        let mut world = Matrix4::identity();
+        world.rotate_x_with_order(pitch, TransformOrder::World);
+        world.rotate_y_with_order(yaw, TransformOrder::World);
+        world.rotate_z_with_order(roll, TransformOrder::World);
+        let q = FQuat::from_pitch_yaw_roll(pitch, yaw, roll, TransformOrder::World);
+        assert_abs_diff_eq!(q.to_matrix(), world, epsilon = 1.0e-5);
+
+        let mut local = Matrix4::identity();
+        local.rotate_x_with_order(pitch, TransformOrder::Local);
+        local.rotate_y_with_order(yaw, TransformOrder::Local);
+        local.rotate_z_with_order(roll, TransformOrder::Local);
+        let q = FQuat::from_pitch_yaw_roll(pitch, yaw, roll, TransformOrder::Local);
+        assert_abs_diff_eq!(q.to_matrix(), local, epsilon = 1.0e-5);
+
+        assert_abs_diff_ne!(world, local, epsilon = 1.0e-3);
+
+        let q = FQuat::from_pitch_yaw_roll(0.0, yaw, 0.0, TransformOrder::default());
+        assert_abs_diff_eq!(q, FQuat::from_axis_angle(FVec3::UNIT_Y, yaw));
+    }
+
+    #[test]
+    fn to_axis_angle() {
+        let axis = FVec3::new(1.0, -2.0, 3.0).normalize();
+        for angle in [0.1, 1.0, FRAC_PI_2, 3.0] {
+            let (actual_axis, actual_angle) = FQuat::from_axis_angle(axis, angle).to_axis_angle();
+            assert_abs_diff_eq!(actual_axis, axis, epsilon = 1.0e-5);
+            assert_abs_diff_eq!(actual_angle, angle, epsilon = 1.0e-5);
+        }
+
+        // Angles beyond PI are equivalent to smaller ones around the opposite axis
+        let (actual_axis, actual_angle) = FQuat::from_axis_angle(axis, 4.0).to_axis_angle();
+        assert_abs_diff_eq!(actual_axis, -axis, epsilon = 1.0e-5);
+        assert_abs_diff_eq!(actual_angle, 2.0 * PI - 4.0, epsilon = 1.0e-5);
+        let (actual_axis, actual_angle) = (-FQuat::from_axis_angle(axis, 1.0)).to_axis_angle();
+        assert_abs_diff_eq!(actual_axis, axis, epsilon = 1.0e-5);
+        assert_abs_diff_eq!(actual_angle, 1.0, epsilon = 1.0e-5);
+
+        assert_eq!(FQuat::identity().to_axis_angle(), (FVec3::UNIT_X, 0.0));
+    }
+
+    #[test]
+    fn rotation_between() {
+        let vectors = [
+            FVec3::UNIT_X,
+            FVec3::UNIT_Y,
+            -FVec3::UNIT_Z,
+            FVec3::new(1.0, 2.0, 3.0),
+            FVec3::new(-0.5, 0.1, -4.0),
+        ];
+        for from in vectors {
+            for to in vectors.into_iter().chain(vectors.map(|v| -v)) {
+                let q = FQuat::rotation_between(from, to);
+                assert_abs_diff_eq!(q.magnitude(), 1.0);
+
+                let rotated = q.to_matrix() * from.extend(0.0);
+                assert_abs_diff_eq!(
+                    rotated.truncate().normalize(),
+                    to.normalize(),
+                    epsilon = 1.0e-5
+                );
+
+                let rotated = Matrix4::rotation_between(from, to) * from.extend(0.0);
+                assert_abs_diff_eq!(
+                    rotated.truncate().normalize(),
+                    to.normalize(),
+                    epsilon = 1.0e-5
+                );
+            }
+        }
+
+        // Parallel vectors need no rotation
+        let v = FVec3::new(1.0, 2.0, 3.0);
+        assert_abs_diff_eq!(FQuat::rotation_between(v, v * 2.0), FQuat::identity());
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        let axes = [
+            FVec3::UNIT_X,
+            FVec3::UNIT_Y,
+            FVec3::UNIT_Z,
+            FVec3::new(1.0, -2.0, 3.0),
+        ];
+        for axis in axes {
+            for angle in [0.0, 0.5, -1.5, 3.0] {
+                let mut mtx = Matrix4::identity();
+                mtx.rotate(axis, angle);
+
+                let q = FQuat::from_matrix(&mtx);
+                assert_abs_diff_eq!(q.magnitude(), 1.0);
+                assert_abs_diff_eq!(q.to_matrix(), mtx, epsilon = 1.0e-5);
+
+                // q and -q represent the same rotation
+                let expected = FQuat::from_axis_angle(axis, angle);
+                assert_abs_diff_eq!(q.dot(expected).abs(), 1.0, epsilon = 1.0e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn from_matrix_branches() {
+        // Half turns have a negative trace, so each of these picks a different
+        // largest diagonal element. A small rotation has a positive trace.
+        let cases = [
+            (FVec3::UNIT_X, PI),
+            (FVec3::UNIT_Y, PI),
+            (FVec3::UNIT_Z, PI),
+            (FVec3::new(1.0, 0.2, 0.1), 2.9),
+            (FVec3::new(0.1, 1.0, -0.2), -2.9),
+            (FVec3::new(-0.2, 0.1, 1.0), 2.9),
+            (FVec3::new(1.0, 1.0, 1.0), 0.1),
+        ];
+
+        for (axis, angle) in cases {
+            let q = FQuat::from_axis_angle(axis, angle);
+            let actual = FQuat::from_matrix(&q.to_matrix());
+
+            // q and -q represent the same rotation
+            let actual = if actual.dot(q) < 0.0 { -actual } else { actual };
+            assert_abs_diff_eq!(actual, q, epsilon = 1.0e-5);
+        }
+    }
+
+    #[test]
+    fn conjugate_and_inverse() {
+        // A simple LCG, just to get some arbitrary but reproducible values
+        let mut seed = 54321_u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) as f32 / u16::MAX as f32 * 2.0 - 1.0
+        };
+
+        for _ in 0..100 {
+            let q = FQuat::new(next(), next(), next(), next()).normalize();
+            let inverse = q.inverse().unwrap();
+            assert_abs_diff_eq!(q * inverse, FQuat::identity(), epsilon = 1.0e-5);
+            assert_abs_diff_eq!(inverse * q, FQuat::identity(), epsilon = 1.0e-5);
+            // Only the same as the conjugate for unit quaternions
+            assert_abs_diff_eq!(q.conjugate(), inverse, epsilon = 1.0e-5);
+
+            let scaled = q * 3.0;
+            let inverse = scaled.inverse().unwrap();
+            assert_abs_diff_eq!(scaled * inverse, FQuat::identity(), epsilon = 1.0e-5);
+            assert_abs_diff_ne!(scaled.conjugate(), inverse, epsilon = 1.0e-5);
+        }
+
+        assert_eq!(FQuat::new(0.0, 0.0, 0.0, 0.0).inverse(), None);
+        assert_eq!(FQuat::identity().conjugate(), FQuat::identity());
+    }
+
+    #[test]
+    fn look_at() {
+        let origin = FVec3::ZERO;
+
+        let q = FQuat::look_at(origin, FVec3::UNIT_Z, FVec3::UNIT_Z, FVec3::UNIT_Y);
+        assert_abs_diff_eq!(q, FQuat::identity());
+
+        // Turning towards +X from +Z is a quarter turn about +Y
+        let q = FQuat::look_at(
+            origin,
+            FVec3::new(3.0, 0.0, 0.0),
+            FVec3::UNIT_Z,
+            FVec3::UNIT_Y,
+        );
+        assert_abs_diff_eq!(q, FQuat::from_axis_angle(FVec3::UNIT_Y, FRAC_PI_2));
+
+        // A target behind the object turns it around `up`
+        let q = FQuat::look_at(origin, -FVec3::UNIT_Z, FVec3::UNIT_Z, FVec3::UNIT_Y);
+        assert_abs_diff_eq!(q * FVec3::UNIT_Z, -FVec3::UNIT_Z, epsilon = 1.0e-5);
+        assert_abs_diff_eq!(q * FVec3::UNIT_Y, FVec3::UNIT_Y, epsilon = 1.0e-5);
+
+        // `forward` doesn't need to be normalized
+        let source = FVec3::new(1.0, 2.0, 3.0);
+        let target = FVec3::new(-4.0, 0.5, 2.0);
+        let q = FQuat::look_at(source, target, FVec3::new(0.0, 0.0, -10.0), FVec3::UNIT_Y);
+        assert_abs_diff_eq!(
+            q * -FVec3::UNIT_Z,
+            (target - source).normalize(),
+            epsilon = 1.0e-5
+        );
+
+        assert_eq!(
+            FQuat::look_at(source, source, FVec3::UNIT_Z, FVec3::UNIT_Y),
+            FQuat::identity()
+        );
+    }
+
+    #[test]
+    fn normalize() {
+        let mut q = FQuat::from_axis_angle(FVec3::new(1.0, 1.0, 0.0), 0.1);
+        for _ in 0..1000 {
+            q = q * FQuat::from_axis_angle(FVec3::new(0.0, 1.0, 1.0), 0.1);
+        }
+        let q = q * 1.01;
+
+        assert_abs_diff_eq!(q.normalize().magnitude(), 1.0);
+        assert_abs_diff_eq!(q.normalize().dot(q.normalize()), 1.0);
+        assert_abs_diff_eq!(q.normalize() * q.magnitude(), q, epsilon = 1.0e-5);
+    }
+
+    #[test]
+    fn slerp() {
+        let start = FQuat::identity();
+        let end = FQuat::identity().rotate_z(FRAC_PI_2);
+
+        assert_abs_diff_eq!(start.slerp(end, 0.0).to_matrix(), start.to_matrix());
+        assert_abs_diff_eq!(start.slerp(end, 1.0).to_matrix(), end.to_matrix());
+
+        let mut expected = Matrix4::identity();
+        expected.rotate_z(FRAC_PI_4);
+        assert_abs_diff_eq!(start.slerp(end, 0.5).to_matrix(), expected);
+
+        // t is not clamped, so this should overshoot to a 180° rotation
+        let mut expected = Matrix4::identity();
+        expected.rotate_z(PI);
+        assert_abs_diff_eq!(start.slerp(end, 2.0).to_matrix(), expected);
+
+        // `-end` is the same rotation, so interpolation should be identical
+        assert_abs_diff_eq!(
+            start.slerp(-end, 0.5).to_matrix(),
+            start.slerp(end, 0.5).to_matrix()
+        );
+    }
+
+    #[test]
+    fn lerp() {
+        let start = FQuat::identity();
+        let end = FQuat::identity().rotate_z(FRAC_PI_2);
+
+        assert_abs_diff_eq!(start.lerp(end, 0.0).to_matrix(), start.to_matrix());
+        assert_abs_diff_eq!(start.lerp(end, 1.0).to_matrix(), end.to_matrix());
+
+        // The halfway point is symmetric, so lerp and slerp agree there
+        let mut expected = Matrix4::identity();
+        expected.rotate_z(FRAC_PI_4);
+        assert_abs_diff_eq!(start.lerp(end, 0.5).to_matrix(), expected);
+    }
+
+    #[test]
+    fn rotate_axes() {
+        let angle = 1.2;
+        let identity = FQuat::identity();
+
+        let x = identity.rotate_x(angle).to_matrix();
+        let y = identity.rotate_y(angle).to_matrix();
+        let z = identity.rotate_z(angle).to_matrix();
+
+        let mut expected = Matrix4::identity();
+        expected.rotate_x(angle);
+        assert_abs_diff_eq!(x, expected);
+
+        let mut expected = Matrix4::identity();
+        expected.rotate_y(angle);
+        assert_abs_diff_eq!(y, expected);
+
+        let mut expected = Matrix4::identity();
+        expected.rotate_z(angle);
+        assert_abs_diff_eq!(z, expected);
+
+        let axis = FVec3::new(0.0, 0.0, 1.0);
+        assert_abs_diff_eq!(identity.rotate(axis, angle).to_matrix(), z);
+    }
+
+    #[test]
+    fn rotate_with_order() {
+        let q = FQuat::from_axis_angle(FVec3::new(1.0, 2.0, -0.5), 0.8);
+        let axis = FVec3::new(-0.3, 1.0, 2.0);
+        let r = FQuat::from_axis_angle(axis, 1.1);
+
+        // World rotations are applied after `q`, and local ones before it
+        let world = q.rotate_with_order(axis, 1.1, TransformOrder::World);
+        let local = q.rotate_with_order(axis, 1.1, TransformOrder::Local);
+        assert_abs_diff_eq!(world, r * q, epsilon = 1.0e-5);
+        assert_abs_diff_eq!(local, q * r, epsilon = 1.0e-5);
+        assert_abs_diff_eq!(q.rotate(axis, 1.1), local);
+
+        // Matching the equivalent matrix transformations
+        for order in [TransformOrder::World, TransformOrder::Local] {
+            let mut expected = q.to_matrix();
+            expected.rotate_x_with_order(0.3, order);
+            expected.rotate_y_with_order(-0.6, order);
+            expected.rotate_z_with_order(1.4, order);
+
+            let actual = q
+                .rotate_x_with_order(0.3, order)
+                .rotate_y_with_order(-0.6, order)
+                .rotate_z_with_order(1.4, order);
+            assert_abs_diff_eq!(actual.to_matrix(), expected, epsilon = 1.0e-5);
+        }
+
+        assert_abs_diff_eq!(
+            q.rotate_x(0.3),
+            q.rotate_x_with_order(0.3, TransformOrder::Local)
+        );
+        assert_abs_diff_eq!(
+            q.rotate_y(0.3),
+            q.rotate_y_with_order(0.3, TransformOrder::Local)
+        );
+        assert_abs_diff_eq!(
+            q.rotate_z(0.3),
+            q.rotate_z_with_order(0.3, TransformOrder::Local)
+        );
+    }
+}
@@ -3,10 +3,22 @@
 use std::fmt;
 
 /// A vector of `f32`s.
+///
+/// Vectors implement [`bytemuck::Pod`], so they can be used directly in vertex
+/// data. Note that the components are stored in WZYX order, so casting a vector
+/// to raw `f32`s or bytes will see its components reversed. An [`FVec3`] always
+/// occupies the same space as an [`FVec4`], with an unused W component.
 #[derive(Clone, Copy)]
+#[repr(transparent)]
 #[doc(alias = "C3D_FVec")]
 pub struct FVec<const N: usize>(pub(crate) citro3d_sys::C3D_FVec);
 
+// SAFETY: C3D_FVec is a union of `f32`s, so it has no padding and every bit
+// pattern (including all zeroes) is valid.
+const _: () = assert!(std::mem::size_of::<citro3d_sys::C3D_FVec>() == 4 * 4);
+unsafe impl<const N: usize> bytemuck::Zeroable for FVec<N> {}
+unsafe impl<const N: usize> bytemuck::Pod for FVec<N> {}
+
 /// A 3-vector of `f32`s.
 pub type FVec3 = FVec<3>;
 
@@ -15,13 +27,68 @@ pub type FVec4 = FVec<4>;
 
 impl<const N: usize> fmt::Debug for FVec<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let inner = unsafe { self.0.__bindgen_anon_1 };
         let type_name = std::any::type_name::<Self>().split("::").last().unwrap();
-        f.debug_tuple(type_name).field(&inner).finish()
+        let mut tuple = f.debug_tuple(type_name);
+
+        // Components are stored in WZYX order, so iterate from back to front
+        // to print them in the order most people would expect.
+        for component in unsafe { self.0.c }.iter().rev().take(N) {
+            tuple.field(component);
+        }
+
+        tuple.finish()
+    }
+}
+
+impl<const N: usize> fmt::Display for FVec<N> {
+    /// Print the vector's components in XYZW order, like `(1, 2, 3)`. Any
+    /// formatting options (e.g. `{:.2}`) are applied to each component.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        for (i, component) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Display::fmt(&component, f)?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl<const N: usize> Default for FVec<N> {
+    /// The zero vector. See [`FVec::zero`].
+    fn default() -> Self {
+        Self::zero()
     }
 }
 
 impl<const N: usize> FVec<N> {
+    /// Construct the zero vector. All components are set to zero, including
+    /// the unused `w` component of an [`FVec3`].
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// const ORIGIN: FVec3 = FVec3::zero();
+    /// assert_eq!(ORIGIN, FVec3::splat(0.0));
+    /// ```
+    pub const fn zero() -> Self {
+        Self(citro3d_sys::C3D_FVec { c: [0.0; 4] })
+    }
+
+    /// Create a vector from a raw [`C3D_FVec`](citro3d_sys::C3D_FVec). For an
+    /// [`FVec3`], the `w` component is ignored.
+    pub const fn from_raw(value: citro3d_sys::C3D_FVec) -> Self {
+        Self(value)
+    }
+
+    /// Consume the vector, returning the underlying
+    /// [`C3D_FVec`](citro3d_sys::C3D_FVec).
+    pub const fn into_raw(self) -> citro3d_sys::C3D_FVec {
+        self.0
+    }
+
     /// The vector's `x` component (also called the `i` component of `ijk[r]`).
     #[doc(alias = "i")]
     pub fn x(self) -> f32 {
@@ -34,20 +101,190 @@ impl<const N: usize> FVec<N> {
         unsafe { self.0.__bindgen_anon_1.y }
     }
 
-    /// The vector's `i` component (also called the `k` component of `ijk[r]`).
+    /// The vector's `z` component (also called the `k` component of `ijk[r]`).
     #[doc(alias = "k")]
     pub fn z(self) -> f32 {
         unsafe { self.0.__bindgen_anon_1.z }
     }
+
+    /// Set the vector's `x` component.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// let mut v = FVec3::splat(0.0);
+    /// v.set_x(1.0);
+    /// assert_eq!(v, FVec3::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn set_x(&mut self, x: f32) {
+        self.0.__bindgen_anon_1.x = x;
+    }
+
+    /// Set the vector's `y` component.
+    pub fn set_y(&mut self, y: f32) {
+        self.0.__bindgen_anon_1.y = y;
+    }
+
+    /// Set the vector's `z` component.
+    pub fn set_z(&mut self, z: f32) {
+        self.0.__bindgen_anon_1.z = z;
+    }
+
+    /// Whether all of the vector's components are finite, i.e. neither
+    /// infinite nor NaN.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// assert!(FVec3::new(1.0, 2.0, 3.0).is_finite());
+    /// assert!(!FVec3::new(1.0, f32::NAN, 3.0).is_finite());
+    /// ```
+    pub fn is_finite(self) -> bool {
+        self.iter().all(f32::is_finite)
+    }
+
+    /// Copy the vector's components into an array, in XYZW order.
+    ///
+    /// Components are stored in reverse order internally, so this can't
+    /// borrow them as a slice, but copying at most four `f32`s is cheap.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// let v = FVec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.as_array(), [1.0, 2.0, 3.0]);
+    /// ```
+    pub fn as_array(&self) -> [f32; N] {
+        std::array::from_fn(|i| self[i])
+    }
+
+    /// The vector's `x` and `y` components. There is no 2-vector type, so
+    /// these are returned as an array.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec4;
+    /// let v = FVec4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v.xy(), [1.0, 2.0]);
+    /// ```
+    pub fn xy(self) -> [f32; 2] {
+        [self.x(), self.y()]
+    }
+
+    /// Iterate over (copies of) the vector's components, in XYZW order.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec4;
+    /// let v = FVec4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v.iter().sum::<f32>(), 10.0);
+    /// ```
+    pub fn iter(&self) -> std::array::IntoIter<f32, N> {
+        self.as_array().into_iter()
+    }
+
+    /// The component-wise minimum of two vectors.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// let a = FVec3::new(1.0, -2.0, 3.0);
+    /// let b = FVec3::new(-1.0, 2.0, 3.5);
+    /// assert_eq!(a.min(b), FVec3::new(-1.0, -2.0, 3.0));
+    /// ```
+    pub fn min(self, rhs: Self) -> Self {
+        self.zip_with(rhs, f32::min)
+    }
+
+    /// The component-wise maximum of two vectors.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// let a = FVec3::new(1.0, -2.0, 3.0);
+    /// let b = FVec3::new(-1.0, 2.0, 3.5);
+    /// assert_eq!(a.max(b), FVec3::new(1.0, 2.0, 3.5));
+    /// ```
+    pub fn max(self, rhs: Self) -> Self {
+        self.zip_with(rhs, f32::max)
+    }
+
+    /// Clamp each component of the vector between the corresponding components
+    /// of `min` and `max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any component of `min` is greater than the corresponding
+    /// component of `max`, or either is NaN (see [`f32::clamp`]).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// let v = FVec3::new(-5.0, 0.5, 5.0);
+    /// let clamped = v.clamp(FVec3::splat(-1.0), FVec3::splat(1.0));
+    /// assert_eq!(clamped, FVec3::new(-1.0, 0.5, 1.0));
+    /// ```
+    pub fn clamp(mut self, min: Self, max: Self) -> Self {
+        for i in (4 - N)..4 {
+            unsafe { self.0.c[i] = self.0.c[i].clamp(min.0.c[i], max.0.c[i]) };
+        }
+        self
+    }
+
+    /// Combine each used component of two vectors with `f`.
+    fn zip_with(mut self, rhs: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        // Only touch the components in use, so an FVec3's W is left alone
+        for i in (4 - N)..4 {
+            unsafe { self.0.c[i] = f(self.0.c[i], rhs.0.c[i]) };
+        }
+        self
+    }
 }
 
 impl FVec4 {
+    /// The zero vector.
+    pub const ZERO: Self = Self::zero();
+
+    /// A vector with all components set to `1.0`.
+    pub const ONE: Self = Self::from_xyzw(1.0, 1.0, 1.0, 1.0);
+
+    /// The unit vector along the +X axis. See [`FVec3::UNIT_X`].
+    pub const UNIT_X: Self = Self::from_xyzw(1.0, 0.0, 0.0, 0.0);
+
+    /// The unit vector along the +Y axis. See [`FVec3::UNIT_Y`].
+    pub const UNIT_Y: Self = Self::from_xyzw(0.0, 1.0, 0.0, 0.0);
+
+    /// The unit vector along the +Z axis. See [`FVec3::UNIT_Z`].
+    pub const UNIT_Z: Self = Self::from_xyzw(0.0, 0.0, 1.0, 0.0);
+
+    /// The unit vector along the +W axis.
+    pub const UNIT_W: Self = Self::from_xyzw(0.0, 0.0, 0.0, 1.0);
+
+    /// `const` equivalent of [`FVec4::new`], for use in the constants above.
+    const fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Self {
+        // Components are stored in WZYX order
+        Self(citro3d_sys::C3D_FVec { c: [w, z, y, x] })
+    }
+
     /// The vector's `w` component (also called `r` for the real component of `ijk[r]`).
     #[doc(alias = "r")]
     pub fn w(self) -> f32 {
         unsafe { self.0.__bindgen_anon_1.w }
     }
 
+    /// Set the vector's `w` component.
+    pub fn set_w(&mut self, w: f32) {
+        self.0.__bindgen_anon_1.w = w;
+    }
+
     /// Create a new [`FVec4`] from its components.
     ///
     /// # Example
@@ -55,12 +292,27 @@ impl FVec4 {
     /// # let _runner = test_runner::GdbRunner::default();
     /// # use citro3d::math::FVec4;
     /// let v = FVec4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!((v.x(), v.y(), v.z(), v.w()), (1.0, 2.0, 3.0, 4.0));
     /// ```
     #[doc(alias = "FVec4_New")]
     pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
         Self(unsafe { citro3d_sys::FVec4_New(x, y, z, w) })
     }
 
+    /// Create a new [`FVec4`] from its components, or [`None`] if any of them
+    /// are not [finite](f32::is_finite).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec4;
+    /// assert!(FVec4::try_new(1.0, 2.0, 3.0, 4.0).is_some());
+    /// assert!(FVec4::try_new(1.0, 2.0, 3.0, f32::INFINITY).is_none());
+    /// ```
+    pub fn try_new(x: f32, y: f32, z: f32, w: f32) -> Option<Self> {
+        Some(Self::new(x, y, z, w)).filter(|v| v.is_finite())
+    }
+
     /// Create a new [`FVec4`], setting each component to `v`.
     ///
     /// # Example
@@ -75,7 +327,33 @@ impl FVec4 {
         Self::new(v, v, v, v)
     }
 
-    /// Divide the vector's XYZ components by its W component.
+    /// Drop the `w` component, converting the vector to an [`FVec3`].
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, FVec4};
+    /// let v = FVec4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v.truncate(), FVec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn truncate(self) -> FVec3 {
+        let mut v = FVec(self.0);
+        // Keep the unused W of an FVec3 zeroed, as FVec3_New does
+        v.0.__bindgen_anon_1.w = 0.0;
+        v
+    }
+
+    /// The vector's `x`, `y` and `z` components. This is the same as
+    /// [`truncate`](Self::truncate).
+    pub fn xyz(self) -> FVec3 {
+        self.truncate()
+    }
+
+    /// Divide the vector's XYZ components by its W component. The resulting
+    /// W component is always `1.0`.
+    ///
+    /// No check is done for a W component of zero, so in that case the XYZ
+    /// components follow the usual `f32` division rules (infinite or NaN).
     ///
     /// # Example
     /// ```
@@ -90,6 +368,24 @@ impl FVec4 {
         Self(unsafe { citro3d_sys::FVec4_PerspDivide(self.0) })
     }
 
+    /// Project a homogeneous coordinate to 3D, by dividing the XYZ components
+    /// by the W component. This is the inverse of [`FVec3::to_homogeneous`].
+    ///
+    /// As with [`perspective_divide`](Self::perspective_divide), a W
+    /// component of zero results in infinite or NaN components.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, FVec4};
+    /// # use approx::assert_abs_diff_eq;
+    /// let v = FVec4::new(2.0, 4.0, 6.0, 2.0);
+    /// assert_abs_diff_eq!(v.project(), FVec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn project(self) -> FVec3 {
+        self.perspective_divide().truncate()
+    }
+
     /// The dot product of two vectors.
     ///
     /// # Example
@@ -103,7 +399,40 @@ impl FVec4 {
     /// ```
     #[doc(alias = "FVec4_Dot")]
     pub fn dot(self, rhs: Self) -> f32 {
-        unsafe { citro3d_sys::FVec4_Dot(self.0, rhs.0) }
+        // Summed in XYZW order, like FVec4_Dot
+        self.x() * rhs.x() + self.y() * rhs.y() + self.z() * rhs.z() + self.w() * rhs.w()
+    }
+
+    /// The dot product of this vector with a point, i.e. `rhs` extended with
+    /// `w = 1.0`. For a plane equation `(normal, d)`, this is the signed
+    /// distance of the point from the plane (scaled by the normal's magnitude).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, FVec4};
+    /// # use approx::assert_abs_diff_eq;
+    /// // The plane y = 1, facing +Y
+    /// let plane = FVec4::new(0.0, 1.0, 0.0, -1.0);
+    /// assert_abs_diff_eq!(plane.dot3(FVec3::new(5.0, 3.0, 5.0)), 2.0);
+    /// ```
+    pub fn dot3(self, rhs: FVec3) -> f32 {
+        self.dot(rhs.extend(1.0))
+    }
+
+    /// The dot product of this vector with a direction, i.e. `rhs` extended
+    /// with `w = 0.0`. This ignores this vector's `w` component.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, FVec4};
+    /// # use approx::assert_abs_diff_eq;
+    /// let plane = FVec4::new(0.0, 1.0, 0.0, -1.0);
+    /// assert_abs_diff_eq!(plane.dot3_dir(FVec3::new(5.0, 3.0, 5.0)), 3.0);
+    /// ```
+    pub fn dot3_dir(self, rhs: FVec3) -> f32 {
+        self.dot(rhs.extend(0.0))
     }
 
     /// The magnitude of the vector.
@@ -121,8 +450,49 @@ impl FVec4 {
         unsafe { citro3d_sys::FVec4_Magnitude(self.0) }
     }
 
+    /// The squared magnitude of the vector. This is cheaper to compute than
+    /// [`magnitude`](Self::magnitude), since it avoids a square root.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec4;
+    /// # use approx::assert_abs_diff_eq;
+    /// let v = FVec4::splat(1.0);
+    /// assert_abs_diff_eq!(v.magnitude_squared(), 4.0);
+    /// ```
+    pub fn magnitude_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    /// The distance between two points in 4D space.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec4;
+    /// # use approx::assert_abs_diff_eq;
+    /// let l = FVec4::new(1.0, 3.0, 4.0, 1.0);
+    /// let r = FVec4::new(0.0, 1.0, 2.0, 1.0);
+    ///
+    /// assert_abs_diff_eq!(l.distance(r), 3.0);
+    /// ```
+    pub fn distance(self, rhs: Self) -> f32 {
+        (self - rhs).magnitude()
+    }
+
+    /// The squared distance between two points in 4D space. This is cheaper
+    /// to compute than [`distance`](Self::distance), and is useful for
+    /// comparing distances without needing their actual value.
+    pub fn distance_squared(self, rhs: Self) -> f32 {
+        (self - rhs).magnitude_squared()
+    }
+
     /// Normalize the vector to a magnitude of `1.0`.
     ///
+    /// Normalizing the zero vector produces non-finite components. Use
+    /// [`try_normalize`](Self::try_normalize) if the vector might be zero.
+    ///
     /// # Example
     /// ```
     /// # let _runner = test_runner::GdbRunner::default();
@@ -135,9 +505,107 @@ impl FVec4 {
     pub fn normalize(self) -> Self {
         Self(unsafe { citro3d_sys::FVec4_Normalize(self.0) })
     }
+
+    /// Normalize the vector to a magnitude of `1.0`, or return [`None`] if the
+    /// vector's magnitude is (approximately) zero.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec4;
+    /// # use approx::assert_abs_diff_eq;
+    /// let v = FVec4::new(1.0, 2.0, 2.0, 4.0);
+    /// assert_abs_diff_eq!(v.try_normalize().unwrap(), FVec4::new(0.2, 0.4, 0.4, 0.8));
+    ///
+    /// assert!(FVec4::splat(0.0).try_normalize().is_none());
+    /// ```
+    pub fn try_normalize(self) -> Option<Self> {
+        if self.magnitude() <= f32::EPSILON {
+            None
+        } else {
+            Some(self.normalize())
+        }
+    }
+
+    /// Linearly interpolate between two vectors. `t` is not clamped, so values
+    /// outside `0.0..=1.0` extrapolate beyond `self` and `rhs`. See
+    /// [`lerp_clamped`](Self::lerp_clamped) for a clamped version.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec4;
+    /// # use approx::assert_abs_diff_eq;
+    /// let a = FVec4::new(0.0, 2.0, -4.0, 1.0);
+    /// let b = FVec4::splat(2.0);
+    /// assert_abs_diff_eq!(a.lerp(b, 0.5), FVec4::new(1.0, 2.0, -1.0, 1.5));
+    /// ```
+    pub fn lerp(self, rhs: Self, t: f32) -> Self {
+        self + (rhs - self) * t
+    }
+
+    /// Linearly interpolate between two vectors, clamping `t` to `0.0..=1.0`.
+    pub fn lerp_clamped(self, rhs: Self, t: f32) -> Self {
+        self.lerp(rhs, t.clamp(0.0, 1.0))
+    }
+
+    /// Scale the vector down so that its magnitude is at most `max_len`.
+    /// Vectors that are already short enough are returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec4;
+    /// # use approx::assert_abs_diff_eq;
+    /// let v = FVec4::new(1.0, -2.0, 2.0, 4.0);
+    /// assert_abs_diff_eq!(v.clamp_length(5.0), v);
+    /// assert_abs_diff_eq!(v.clamp_length(1.5).magnitude(), 1.5);
+    /// ```
+    pub fn clamp_length(self, max_len: f32) -> Self {
+        let magnitude = self.magnitude();
+        if magnitude > max_len {
+            self * (max_len / magnitude)
+        } else {
+            self
+        }
+    }
 }
 
 impl FVec3 {
+    /// The zero vector.
+    pub const ZERO: Self = Self::zero();
+
+    /// A vector with all components set to `1.0`.
+    pub const ONE: Self = Self::from_xyz(1.0, 1.0, 1.0);
+
+    /// The unit vector along the +X axis, which points right.
+    pub const UNIT_X: Self = Self::from_xyz(1.0, 0.0, 0.0);
+
+    /// The unit vector along the +Y axis, which points up.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{CoordinateOrientation, FVec3, Matrix4};
+    /// let view = Matrix4::looking_at(
+    ///     FVec3::new(0.0, 0.0, 5.0),
+    ///     FVec3::ZERO,
+    ///     FVec3::UNIT_Y,
+    ///     CoordinateOrientation::RightHanded,
+    /// );
+    /// ```
+    pub const UNIT_Y: Self = Self::from_xyz(0.0, 1.0, 0.0);
+
+    /// The unit vector along the +Z axis. Whether this points into or out of
+    /// the screen depends on the [`CoordinateOrientation`](super::CoordinateOrientation).
+    pub const UNIT_Z: Self = Self::from_xyz(0.0, 0.0, 1.0);
+
+    /// `const` equivalent of [`FVec3::new`], for use in the constants above.
+    const fn from_xyz(x: f32, y: f32, z: f32) -> Self {
+        // Components are stored in WZYX order, and W is unused
+        Self(citro3d_sys::C3D_FVec { c: [0.0, z, y, x] })
+    }
+
     /// Create a new [`FVec3`] from its components.
     ///
     /// # Example
@@ -145,12 +613,27 @@ impl FVec3 {
     /// # let _runner = test_runner::GdbRunner::default();
     /// # use citro3d::math::FVec3;
     /// let v = FVec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!((v.x(), v.y(), v.z()), (1.0, 2.0, 3.0));
     /// ```
     #[doc(alias = "FVec3_New")]
     pub fn new(x: f32, y: f32, z: f32) -> Self {
         Self(unsafe { citro3d_sys::FVec3_New(x, y, z) })
     }
 
+    /// Create a new [`FVec3`] from its components, or [`None`] if any of them
+    /// are not [finite](f32::is_finite).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// assert!(FVec3::try_new(1.0, 2.0, 3.0).is_some());
+    /// assert!(FVec3::try_new(f32::NAN, 2.0, 3.0).is_none());
+    /// ```
+    pub fn try_new(x: f32, y: f32, z: f32) -> Option<Self> {
+        Some(Self::new(x, y, z)).filter(|v| v.is_finite())
+    }
+
     /// Create a new [`FVec3`], setting each component to the given `v`.
     ///
     /// # Example
@@ -163,6 +646,36 @@ impl FVec3 {
         Self::new(v, v, v)
     }
 
+    /// Add a `w` component, converting the vector to an [`FVec4`].
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, FVec4};
+    /// let v = FVec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.extend(4.0), FVec4::new(1.0, 2.0, 3.0, 4.0));
+    /// ```
+    pub fn extend(self, w: f32) -> FVec4 {
+        let mut v = FVec(self.0);
+        v.set_w(w);
+        v
+    }
+
+    /// Convert the vector to a homogeneous coordinate, i.e. with a `w`
+    /// component of `1.0`, so that it can be transformed by a [`Matrix4`](super::Matrix4)
+    /// as a point.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, FVec4};
+    /// let v = FVec3::new(1.0, 2.0, 3.0);
+    /// assert_eq!(v.to_homogeneous(), FVec4::new(1.0, 2.0, 3.0, 1.0));
+    /// ```
+    pub fn to_homogeneous(self) -> FVec4 {
+        self.extend(1.0)
+    }
+
     /// The distance between two points in 3D space.
     ///
     /// # Example
@@ -180,8 +693,31 @@ impl FVec3 {
         unsafe { citro3d_sys::FVec3_Distance(self.0, rhs.0) }
     }
 
+    /// The squared distance between two points in 3D space. This is cheaper
+    /// to compute than [`distance`](Self::distance), and is useful for
+    /// comparing distances without needing their actual value.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// # use approx::assert_abs_diff_eq;
+    /// let l = FVec3::new(1.0, 3.0, 4.0);
+    /// let r = FVec3::new(0.0, 1.0, 2.0);
+    ///
+    /// assert_abs_diff_eq!(l.distance_squared(r), 9.0);
+    /// ```
+    pub fn distance_squared(self, rhs: Self) -> f32 {
+        (self - rhs).magnitude_squared()
+    }
+
     /// The cross product of two 3D vectors.
     ///
+    /// The result is always computed such that `X × Y = Z`, so it follows the
+    /// right-hand rule in a [`RightHanded`](super::CoordinateOrientation::RightHanded)
+    /// coordinate system and the left-hand rule in a
+    /// [`LeftHanded`](super::CoordinateOrientation::LeftHanded) one.
+    ///
     /// # Example
     /// ```
     /// # let _runner = test_runner::GdbRunner::default();
@@ -193,7 +729,70 @@ impl FVec3 {
     /// ```
     #[doc(alias = "FVec3_Cross")]
     pub fn cross(self, rhs: Self) -> Self {
-        Self(unsafe { citro3d_sys::FVec3_Cross(self.0, rhs.0) })
+        Self::from_xyz(
+            self.y() * rhs.z() - self.z() * rhs.y(),
+            self.z() * rhs.x() - self.x() * rhs.z(),
+            self.x() * rhs.y() - self.y() * rhs.x(),
+        )
+    }
+
+    /// The unsigned angle between two vectors, in radians, in the range `0..=π`.
+    ///
+    /// If either vector is zero, the angle is undefined and NaN is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// # use approx::assert_abs_diff_eq;
+    /// # use std::f32::consts::FRAC_PI_2;
+    /// let angle = FVec3::UNIT_X.angle_between(FVec3::new(0.0, 2.0, 0.0));
+    /// assert_abs_diff_eq!(angle, FRAC_PI_2);
+    /// ```
+    pub fn angle_between(self, rhs: Self) -> f32 {
+        let cos = self.dot(rhs) / (self.magnitude() * rhs.magnitude());
+        // Rounding error can push nearly-parallel vectors slightly outside the
+        // domain of acos, which would result in NaN
+        cos.clamp(-1.0, 1.0).acos()
+    }
+
+    /// The signed angle from `self` to `rhs`, in radians, in the range `-π..=π`.
+    /// The angle is positive if rotating `self` to `rhs` is a counterclockwise
+    /// rotation around `axis`, using the same handedness as [`cross`](Self::cross).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// # use approx::assert_abs_diff_eq;
+    /// # use std::f32::consts::FRAC_PI_2;
+    /// let (x, y) = (FVec3::UNIT_X, FVec3::UNIT_Y);
+    /// assert_abs_diff_eq!(x.signed_angle_between(y, FVec3::UNIT_Z), FRAC_PI_2);
+    /// assert_abs_diff_eq!(y.signed_angle_between(x, FVec3::UNIT_Z), -FRAC_PI_2);
+    /// ```
+    pub fn signed_angle_between(self, rhs: Self, axis: Self) -> f32 {
+        let angle = self.angle_between(rhs);
+        if self.cross(rhs).dot(axis) < 0.0 {
+            -angle
+        } else {
+            angle
+        }
+    }
+
+    /// Reflect the vector off a surface with the given `normal`, which is
+    /// assumed to be normalized.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// # use approx::assert_abs_diff_eq;
+    /// let velocity = FVec3::new(1.0, -1.0, 0.0);
+    /// let bounced = velocity.reflect(FVec3::UNIT_Y);
+    /// assert_abs_diff_eq!(bounced, FVec3::new(1.0, 1.0, 0.0));
+    /// ```
+    pub fn reflect(self, normal: Self) -> Self {
+        self - normal * (2.0 * self.dot(normal))
     }
 
     /// The dot product of two vectors.
@@ -209,7 +808,7 @@ impl FVec3 {
     /// ```
     #[doc(alias = "FVec3_Dot")]
     pub fn dot(self, rhs: Self) -> f32 {
-        unsafe { citro3d_sys::FVec3_Dot(self.0, rhs.0) }
+        self.x() * rhs.x() + self.y() * rhs.y() + self.z() * rhs.z()
     }
 
     /// The magnitude of the vector.
@@ -227,8 +826,26 @@ impl FVec3 {
         unsafe { citro3d_sys::FVec3_Magnitude(self.0) }
     }
 
+    /// The squared magnitude of the vector. This is cheaper to compute than
+    /// [`magnitude`](Self::magnitude), since it avoids a square root.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// # use approx::assert_abs_diff_eq;
+    /// let v = FVec3::new(1.0, 2.0, 2.0);
+    /// assert_abs_diff_eq!(v.magnitude_squared(), 9.0);
+    /// ```
+    pub fn magnitude_squared(self) -> f32 {
+        self.dot(self)
+    }
+
     /// Normalize the vector to a magnitude of `1.0`.
     ///
+    /// Normalizing the zero vector produces non-finite components. Use
+    /// [`try_normalize`](Self::try_normalize) if the vector might be zero.
+    ///
     /// # Example
     /// ```
     /// # let _runner = test_runner::GdbRunner::default();
@@ -241,13 +858,158 @@ impl FVec3 {
     pub fn normalize(self) -> Self {
         Self(unsafe { citro3d_sys::FVec3_Normalize(self.0) })
     }
+
+    /// Normalize the vector to a magnitude of `1.0`, or return [`None`] if the
+    /// vector's magnitude is (approximately) zero.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// # use approx::assert_abs_diff_eq;
+    /// let v = FVec3::new(0.0, 3.0, 4.0);
+    /// assert_abs_diff_eq!(v.try_normalize().unwrap(), FVec3::new(0.0, 0.6, 0.8));
+    ///
+    /// assert!(FVec3::splat(0.0).try_normalize().is_none());
+    /// ```
+    pub fn try_normalize(self) -> Option<Self> {
+        if self.magnitude() <= f32::EPSILON {
+            None
+        } else {
+            Some(self.normalize())
+        }
+    }
+
+    /// Linearly interpolate between two vectors. `t` is not clamped, so values
+    /// outside `0.0..=1.0` extrapolate beyond `self` and `rhs`. See
+    /// [`lerp_clamped`](Self::lerp_clamped) for a clamped version.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// # use approx::assert_abs_diff_eq;
+    /// let a = FVec3::new(0.0, 2.0, -4.0);
+    /// let b = FVec3::splat(2.0);
+    /// assert_abs_diff_eq!(a.lerp(b, 0.5), FVec3::new(1.0, 2.0, -1.0));
+    /// ```
+    pub fn lerp(self, rhs: Self, t: f32) -> Self {
+        self + (rhs - self) * t
+    }
+
+    /// Linearly interpolate between two vectors, clamping `t` to `0.0..=1.0`.
+    pub fn lerp_clamped(self, rhs: Self, t: f32) -> Self {
+        self.lerp(rhs, t.clamp(0.0, 1.0))
+    }
+
+    /// Scale the vector down so that its magnitude is at most `max_len`.
+    /// Vectors that are already short enough are returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// # use approx::assert_abs_diff_eq;
+    /// let v = FVec3::new(1.0, -2.0, 2.0);
+    /// assert_abs_diff_eq!(v.clamp_length(5.0), v);
+    /// assert_abs_diff_eq!(v.clamp_length(1.5).magnitude(), 1.5);
+    /// ```
+    pub fn clamp_length(self, max_len: f32) -> Self {
+        let magnitude = self.magnitude();
+        if magnitude > max_len {
+            self * (max_len / magnitude)
+        } else {
+            self
+        }
+    }
+}
+
+impl From<[f32; 4]> for FVec4 {
+    fn from([x, y, z, w]: [f32; 4]) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for FVec4 {
+    fn from((x, y, z, w): (f32, f32, f32, f32)) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<FVec4> for [f32; 4] {
+    fn from(v: FVec4) -> Self {
+        [v.x(), v.y(), v.z(), v.w()]
+    }
+}
+
+impl From<FVec4> for (f32, f32, f32, f32) {
+    fn from(v: FVec4) -> Self {
+        (v.x(), v.y(), v.z(), v.w())
+    }
+}
+
+impl From<[f32; 3]> for FVec3 {
+    fn from([x, y, z]: [f32; 3]) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<(f32, f32, f32)> for FVec3 {
+    fn from((x, y, z): (f32, f32, f32)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl From<FVec3> for [f32; 3] {
+    fn from(v: FVec3) -> Self {
+        [v.x(), v.y(), v.z()]
+    }
+}
+
+impl From<FVec3> for (f32, f32, f32) {
+    fn from(v: FVec3) -> Self {
+        (v.x(), v.y(), v.z())
+    }
+}
+
+impl From<FVec4> for FVec3 {
+    /// Drop the `w` component. See [`FVec4::truncate`].
+    fn from(v: FVec4) -> Self {
+        v.truncate()
+    }
+}
+
+impl<const N: usize> TryFrom<&[f32]> for FVec<N>
+where
+    Self: From<[f32; N]>,
+{
+    type Error = std::array::TryFromSliceError;
+
+    /// Build a vector from a slice of components in XYZW order, failing if
+    /// the slice doesn't contain exactly `N` components.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// let data = vec![1.0, 2.0, 3.0, 4.0];
+    /// let v = FVec3::try_from(&data[..3]).unwrap();
+    /// assert_eq!(v, FVec3::new(1.0, 2.0, 3.0));
+    /// assert!(FVec3::try_from(&data[..]).is_err());
+    /// ```
+    fn try_from(components: &[f32]) -> Result<Self, Self::Error> {
+        <[f32; N]>::try_from(components).map(Self::from)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use approx::assert_abs_diff_eq;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use approx::{assert_abs_diff_eq, assert_relative_eq};
 
     use super::*;
+    use crate::math::{AspectRatio, ClipPlanes, Matrix4, Projection};
 
     #[test]
     fn fvec4() {
@@ -264,4 +1026,495 @@ mod tests {
         let expected = [1.0, 2.0, 3.0];
         assert_abs_diff_eq!(&actual[..], &expected[..]);
     }
+
+    #[test]
+    fn conversions() {
+        let v = FVec4::from([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(v, FVec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(<[f32; 4]>::from(v), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(FVec4::from((1.0, 2.0, 3.0, 4.0)), v);
+        assert_eq!(<(f32, f32, f32, f32)>::from(v), (1.0, 2.0, 3.0, 4.0));
+
+        let v = FVec3::from([1.0, 2.0, 3.0]);
+        assert_eq!(v, FVec3::new(1.0, 2.0, 3.0));
+        assert_eq!(<[f32; 3]>::from(v), [1.0, 2.0, 3.0]);
+        assert_eq!(FVec3::from((1.0, 2.0, 3.0)), v);
+        assert_eq!(<(f32, f32, f32)>::from(v), (1.0, 2.0, 3.0));
+
+        assert_eq!(v.extend(1.0).truncate(), v);
+        assert_eq!(v.extend(-2.0).w(), -2.0);
+        assert_eq!(FVec3::from(FVec4::new(1.0, 2.0, 3.0, 4.0)), v);
+        assert_eq!(unsafe { FVec4::splat(5.0).truncate().0.c[0] }, 0.0);
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+        let v = FVec3::try_from(&data[..3]).unwrap();
+        assert_eq!((v.x(), v.y(), v.z()), (1.0, 2.0, 3.0));
+        assert_eq!(unsafe { v.0.c }, [0.0, 3.0, 2.0, 1.0]);
+
+        let v = FVec4::try_from(&data[1..]).unwrap();
+        assert_eq!((v.x(), v.y(), v.z(), v.w()), (2.0, 3.0, 4.0, 5.0));
+
+        assert!(FVec3::try_from(&data[..2]).is_err());
+        assert!(FVec3::try_from(&data[..4]).is_err());
+        assert!(FVec4::try_from(&data[..]).is_err());
+        assert!(FVec4::try_from(&[][..]).is_err());
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(unsafe { FVec3::zero().0.c }, [0.0; 4]);
+        assert_eq!(unsafe { FVec4::zero().0.c }, [0.0; 4]);
+        assert_eq!(unsafe { FVec3::default().0.c }, [0.0; 4]);
+        assert_eq!(FVec4::default(), FVec4::splat(0.0));
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(FVec3::ZERO, FVec3::zero());
+        assert_eq!(FVec3::ONE, FVec3::splat(1.0));
+        assert_eq!(FVec3::UNIT_X, FVec3::new(1.0, 0.0, 0.0));
+        assert_eq!(FVec3::UNIT_Y, FVec3::new(0.0, 1.0, 0.0));
+        assert_eq!(FVec3::UNIT_Z, FVec3::new(0.0, 0.0, 1.0));
+        assert_eq!(unsafe { FVec3::ONE.0.c[0] }, 0.0);
+
+        assert_eq!(FVec4::ZERO, FVec4::zero());
+        assert_eq!(FVec4::ONE, FVec4::splat(1.0));
+        assert_eq!(FVec4::UNIT_X, FVec4::new(1.0, 0.0, 0.0, 0.0));
+        assert_eq!(FVec4::UNIT_Y, FVec4::new(0.0, 1.0, 0.0, 0.0));
+        assert_eq!(FVec4::UNIT_Z, FVec4::new(0.0, 0.0, 1.0, 0.0));
+        assert_eq!(FVec4::UNIT_W, FVec4::new(0.0, 0.0, 0.0, 1.0));
+
+        assert_eq!(FVec3::UNIT_X.cross(FVec3::UNIT_Y), FVec3::UNIT_Z);
+    }
+
+    #[test]
+    fn bytemuck() {
+        let v = FVec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(bytemuck::cast::<_, [f32; 4]>(v), [4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(bytemuck::bytes_of(&v).len(), 16);
+        assert_eq!(bytemuck::bytes_of(&FVec3::new(1.0, 2.0, 3.0)).len(), 16);
+
+        let vertices = [FVec3::new(1.0, 2.0, 3.0), FVec3::new(4.0, 5.0, 6.0)];
+        let floats: &[f32] = bytemuck::cast_slice(&vertices);
+        assert_eq!(floats, [0.0, 3.0, 2.0, 1.0, 0.0, 6.0, 5.0, 4.0]);
+
+        assert_eq!(<FVec3 as bytemuck::Zeroable>::zeroed(), FVec3::zero());
+    }
+
+    #[test]
+    fn swizzle() {
+        let v = FVec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.xyz(), FVec3::new(1.0, 2.0, 3.0));
+        assert_eq!(unsafe { v.xyz().0.__bindgen_anon_1.w }, 0.0);
+        assert_eq!(v.xy(), [1.0, 2.0]);
+        assert_eq!(v.xyz().xy(), [1.0, 2.0]);
+    }
+
+    #[test]
+    fn as_array_and_iter() {
+        let v = FVec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.as_array(), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(v.iter().collect::<Vec<_>>(), [1.0, 2.0, 3.0, 4.0]);
+
+        let v = FVec3::new(1.0, 2.0, 3.0);
+        assert_eq!(v.as_array(), [1.0, 2.0, 3.0]);
+        assert_eq!(v.iter().collect::<Vec<_>>(), [1.0, 2.0, 3.0]);
+        assert_eq!(v.iter().len(), 3);
+    }
+
+    #[test]
+    fn try_new() {
+        let subnormal = f32::MIN_POSITIVE / 2.0;
+        assert!(subnormal.is_subnormal());
+
+        assert_eq!(
+            FVec3::try_new(1.0, 2.0, 3.0),
+            Some(FVec3::new(1.0, 2.0, 3.0))
+        );
+        assert!(FVec3::try_new(subnormal, -subnormal, 0.0).is_some());
+        assert_eq!(FVec3::try_new(f32::NAN, 2.0, 3.0), None);
+        assert_eq!(FVec3::try_new(1.0, f32::INFINITY, 3.0), None);
+        assert_eq!(FVec3::try_new(1.0, 2.0, f32::NEG_INFINITY), None);
+
+        assert!(FVec4::try_new(1.0, 2.0, 3.0, subnormal).is_some());
+        assert_eq!(FVec4::try_new(1.0, 2.0, 3.0, f32::NAN), None);
+        assert_eq!(FVec4::try_new(f32::INFINITY, 2.0, 3.0, 4.0), None);
+
+        assert!(!FVec3::zero().normalize().is_finite());
+
+        // The unused W of an FVec3 doesn't affect whether it's finite
+        let v = FVec4::new(1.0, 2.0, 3.0, f32::NAN);
+        assert!(!v.is_finite());
+        let garbage_w: FVec3 = FVec(v.0);
+        assert!(garbage_w.is_finite());
+    }
+
+    #[test]
+    fn setters() {
+        let mut v = FVec4::splat(0.0);
+        v.set_x(1.0);
+        v.set_y(2.0);
+        v.set_z(3.0);
+        v.set_w(4.0);
+        assert_eq!(v, FVec4::new(1.0, 2.0, 3.0, 4.0));
+
+        let mut v = FVec3::splat(0.0);
+        v.set_x(1.0);
+        v.set_y(2.0);
+        v.set_z(3.0);
+        assert_eq!(v, FVec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", FVec4::new(1.0, 2.0, 3.0, 4.0)),
+            "FVec<4>(1.0, 2.0, 3.0, 4.0)"
+        );
+        assert_eq!(
+            format!("{:?}", FVec3::new(1.0, 2.0, 3.0)),
+            "FVec<3>(1.0, 2.0, 3.0)"
+        );
+    }
+
+    #[test]
+    fn display() {
+        let v = FVec3::new(1.0, -2.5, 1.0 / 3.0);
+        assert_eq!(format!("{v}"), "(1, -2.5, 0.33333334)");
+        assert_eq!(format!("{v:.2}"), "(1.00, -2.50, 0.33)");
+        assert_eq!(format!("{v:5.1}"), "(  1.0,  -2.5,   0.3)");
+
+        let v = FVec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(format!("{v:.1}"), "(1.0, 2.0, 3.0, 4.0)");
+    }
+
+    #[test]
+    fn project() {
+        let v = FVec3::new(1.0, -2.0, 3.0);
+        assert_eq!(v.to_homogeneous().project(), v);
+        assert_abs_diff_eq!((v.to_homogeneous() * 4.0).project(), v);
+
+        let v = FVec4::new(1.0, -1.0, 0.0, 0.0).project();
+        assert_eq!(v.x(), f32::INFINITY);
+        assert_eq!(v.y(), f32::NEG_INFINITY);
+        assert!(v.z().is_nan());
+
+        let projection: Matrix4 = Projection::perspective(
+            FRAC_PI_4,
+            AspectRatio::TopScreen,
+            ClipPlanes {
+                near: 0.1,
+                far: 100.0,
+            },
+        )
+        .into();
+
+        // Right-handed by default, so a point in front of the camera is at -Z
+        for point in [
+            FVec3::new(0.0, 0.0, -10.0),
+            FVec3::new(1.0, -1.0, -5.0),
+            FVec3::new(-3.0, 2.0, -50.0),
+        ] {
+            let ndc = (projection * point.to_homogeneous()).project();
+            for component in ndc.iter() {
+                assert!((-1.0..=1.0).contains(&component), "{ndc} is off-screen");
+            }
+        }
+
+        // Far off to the side of the camera
+        let ndc = (projection * FVec3::new(100.0, 0.0, -1.0).to_homogeneous()).project();
+        assert!(ndc.iter().any(|component| component.abs() > 1.0));
+    }
+
+    #[test]
+    fn perspective_divide() {
+        let v = FVec4::new(-2.0, 4.0, 6.0, -2.0).perspective_divide();
+        assert_abs_diff_eq!(v, FVec4::new(1.0, -2.0, -3.0, 1.0));
+
+        let v = FVec4::new(2.0, -4.0, 0.0, 0.0).perspective_divide();
+        assert_eq!(v.x(), f32::INFINITY);
+        assert_eq!(v.y(), f32::NEG_INFINITY);
+        assert!(v.z().is_nan());
+    }
+
+    #[test]
+    fn fvec3_cross() {
+        let x = FVec3::new(1.0, 0.0, 0.0);
+        let y = FVec3::new(0.0, 1.0, 0.0);
+        let z = FVec3::new(0.0, 0.0, 1.0);
+
+        assert_abs_diff_eq!(x.cross(y), z);
+        assert_abs_diff_eq!(y.cross(z), x);
+        assert_abs_diff_eq!(z.cross(x), y);
+        assert_abs_diff_eq!(y.cross(x), -z);
+
+        let v = FVec3::new(1.0, 2.0, 3.0);
+        assert_abs_diff_eq!(v.cross(v), FVec3::splat(0.0));
+    }
+
+    #[test]
+    fn fvec3_magnitude() {
+        let v = FVec3::new(-2.0, 3.0, -6.0);
+        assert_abs_diff_eq!(v.magnitude(), 7.0);
+        assert_abs_diff_eq!(v.magnitude_squared(), 49.0);
+
+        let zero = FVec3::splat(0.0);
+        assert_abs_diff_eq!(zero.magnitude(), 0.0);
+        assert_abs_diff_eq!(zero.magnitude_squared(), 0.0);
+        assert_abs_diff_eq!(v.distance(zero), 7.0);
+        assert_abs_diff_eq!(zero.distance(v), 7.0);
+        assert_abs_diff_eq!(v.distance(v), 0.0);
+    }
+
+    #[test]
+    fn lerp() {
+        let a = FVec3::new(1.0, -2.0, 3.0);
+        let b = FVec3::new(3.0, 2.0, -1.0);
+        assert_abs_diff_eq!(a.lerp(b, 0.0), a);
+        assert_abs_diff_eq!(a.lerp(b, 0.5), FVec3::new(2.0, 0.0, 1.0));
+        assert_abs_diff_eq!(a.lerp(b, 1.0), b);
+        assert_abs_diff_eq!(a.lerp(b, 2.0), FVec3::new(5.0, 6.0, -5.0));
+        assert_abs_diff_eq!(a.lerp(b, -1.0), FVec3::new(-1.0, -6.0, 7.0));
+        assert_abs_diff_eq!(a.lerp_clamped(b, 2.0), b);
+        assert_abs_diff_eq!(a.lerp_clamped(b, -1.0), a);
+        assert_abs_diff_eq!(a.lerp_clamped(b, 0.5), a.lerp(b, 0.5));
+        assert_abs_diff_eq!(a.lerp(b, 0.5), (a + b) / 2.0);
+
+        let a = FVec4::new(1.0, -2.0, 3.0, 0.0);
+        let b = FVec4::new(3.0, 2.0, -1.0, 4.0);
+        assert_abs_diff_eq!(a.lerp(b, 0.0), a);
+        assert_abs_diff_eq!(a.lerp(b, 0.5), FVec4::new(2.0, 0.0, 1.0, 2.0));
+        assert_abs_diff_eq!(a.lerp(b, 0.5), (a + b) / 2.0);
+        assert_abs_diff_eq!(a.lerp(b, 1.0), b);
+        assert_abs_diff_eq!(a.lerp(b, 2.0), FVec4::new(5.0, 6.0, -5.0, 8.0));
+        assert_abs_diff_eq!(a.lerp(b, -1.0), FVec4::new(-1.0, -6.0, 7.0, -4.0));
+        assert_abs_diff_eq!(a.lerp_clamped(b, 2.0), b);
+        assert_abs_diff_eq!(a.lerp_clamped(b, -1.0), a);
+    }
+
+    #[test]
+    fn min_max_clamp() {
+        let a = FVec3::new(-3.0, 2.0, -0.5);
+        let b = FVec3::new(1.0, -4.0, -0.25);
+        assert_eq!(a.min(b), FVec3::new(-3.0, -4.0, -0.5));
+        assert_eq!(a.max(b), FVec3::new(1.0, 2.0, -0.25));
+
+        let lo = FVec3::new(-1.0, -1.0, 0.0);
+        let hi = FVec3::new(1.0, 1.0, 2.0);
+        assert_eq!(a.clamp(lo, hi), FVec3::new(-1.0, 1.0, 0.0));
+
+        let unit_box = (FVec3::splat(-1.0), FVec3::splat(1.0));
+        let clamped = FVec3::new(5.0, -5.0, 0.0).clamp(unit_box.0, unit_box.1);
+        assert_eq!(clamped, FVec3::new(1.0, -1.0, 0.0));
+        // The unused W component isn't clamped
+        assert_eq!(unsafe { clamped.0.c[0] }, 0.0);
+        let mut min = unit_box.0;
+        min.0.__bindgen_anon_1.w = 2.0;
+        assert_eq!(unsafe { clamped.clamp(min, unit_box.1).0.c[0] }, 0.0);
+
+        let a = FVec4::new(-3.0, 2.0, -0.5, 7.0);
+        let b = FVec4::new(1.0, -4.0, -0.25, -7.0);
+        assert_eq!(a.min(b), FVec4::new(-3.0, -4.0, -0.5, -7.0));
+        assert_eq!(a.max(b), FVec4::new(1.0, 2.0, -0.25, 7.0));
+        assert_eq!(
+            a.clamp(FVec4::splat(-1.0), FVec4::splat(1.0)),
+            FVec4::new(-1.0, 1.0, -0.5, 1.0)
+        );
+    }
+
+    #[test]
+    fn clamp_length() {
+        let v = FVec3::new(-3.0, 0.0, 4.0);
+        assert_eq!(v.clamp_length(10.0), v);
+        assert_abs_diff_eq!(v.clamp_length(2.5), FVec3::new(-1.5, 0.0, 2.0));
+        assert_eq!(FVec3::zero().clamp_length(1.0), FVec3::zero());
+
+        let v = FVec4::new(-3.0, 0.0, 4.0, 0.0);
+        assert_abs_diff_eq!(v.clamp_length(2.5), FVec4::new(-1.5, 0.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn reflect() {
+        let up = FVec3::UNIT_Y;
+        assert_abs_diff_eq!(
+            FVec3::new(1.0, -1.0, 0.0).reflect(up),
+            FVec3::new(1.0, 1.0, 0.0)
+        );
+        // Vectors parallel to the surface are unchanged
+        assert_abs_diff_eq!(
+            FVec3::new(2.0, 0.0, -3.0).reflect(up),
+            FVec3::new(2.0, 0.0, -3.0)
+        );
+        assert_abs_diff_eq!(-up.reflect(up), up);
+
+        let normal = FVec3::new(1.0, 1.0, 1.0).normalize();
+        let v = FVec3::new(3.0, -2.0, 0.5);
+        let reflected = v.reflect(normal);
+        assert_abs_diff_eq!(reflected.magnitude(), v.magnitude(), epsilon = 1.0e-5);
+        assert_abs_diff_eq!(reflected.dot(normal), -v.dot(normal), epsilon = 1.0e-5);
+        assert_abs_diff_eq!(reflected.reflect(normal), v, epsilon = 1.0e-5);
+    }
+
+    #[test]
+    fn angle_between() {
+        let v = FVec3::new(1.0, 2.0, 3.0);
+        // acos is very sensitive to rounding error near ±1, so (anti)parallel
+        // vectors can't be checked as precisely
+        assert_abs_diff_eq!(v.angle_between(v), 0.0, epsilon = 1.0e-3);
+        assert_abs_diff_eq!(v.angle_between(v * 3.0), 0.0, epsilon = 1.0e-3);
+        assert_abs_diff_eq!(v.angle_between(-v), PI, epsilon = 1.0e-3);
+        assert_abs_diff_eq!(v.angle_between(v.cross(FVec3::UNIT_X)), FRAC_PI_2);
+        assert!(FVec3::new(0.1, 0.2, 0.3).angle_between(v).is_finite());
+
+        // The cosine must be clamped for nearly-parallel vectors, or acos may
+        // return NaN due to rounding error
+        for i in 1..100 {
+            let v = FVec3::new(i as f32 * 0.37, -(i as f32).sqrt(), 1.0 / i as f32);
+            for scale in [1.0, 0.1, 7.3, 1.0e3] {
+                assert!(v.angle_between(v * scale).is_finite());
+                assert!(v.angle_between(-v * scale).is_finite());
+            }
+        }
+
+        let up = FVec3::UNIT_Y;
+        let (x, z) = (FVec3::UNIT_X, FVec3::UNIT_Z);
+        assert_abs_diff_eq!(z.signed_angle_between(x, up), FRAC_PI_2);
+        assert_abs_diff_eq!(x.signed_angle_between(z, up), -FRAC_PI_2);
+        assert_abs_diff_eq!(x.signed_angle_between(-x, up).abs(), PI, epsilon = 1.0e-3);
+        assert_abs_diff_eq!(x.signed_angle_between(x, up), 0.0);
+    }
+
+    #[test]
+    fn fvec3_distance() {
+        let points = [
+            FVec3::new(0.0, 0.0, 0.0),
+            FVec3::new(1.0, -2.0, 3.0),
+            FVec3::new(-4.0, 0.5, 2.0),
+            FVec3::new(10.0, 10.0, -10.0),
+        ];
+
+        for a in points {
+            for b in points {
+                assert_abs_diff_eq!(a.distance(b), b.distance(a));
+                assert_relative_eq!(
+                    a.distance_squared(b),
+                    a.distance(b) * a.distance(b),
+                    max_relative = 1.0e-5
+                );
+
+                for c in points {
+                    assert!(a.distance(c) <= a.distance(b) + b.distance(c) + f32::EPSILON);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fvec4_magnitude() {
+        let v = FVec4::new(-1.0, 2.0, -2.0, 4.0);
+        assert_abs_diff_eq!(v.magnitude(), 5.0);
+        assert_abs_diff_eq!(v.magnitude_squared(), 25.0);
+
+        let zero = FVec4::splat(0.0);
+        assert_abs_diff_eq!(zero.magnitude(), 0.0);
+        assert_abs_diff_eq!(zero.magnitude_squared(), 0.0);
+        assert_abs_diff_eq!(v.distance(zero), 5.0);
+        assert_abs_diff_eq!(zero.distance(v), 5.0);
+        assert_abs_diff_eq!(v.distance(v), 0.0);
+    }
+
+    #[test]
+    fn fvec3_normalize() {
+        let v = FVec3::new(-3.0, 0.5, 12.0);
+        assert_abs_diff_eq!(v.normalize().magnitude(), 1.0);
+        assert_abs_diff_eq!(v.try_normalize().unwrap().magnitude(), 1.0);
+
+        assert!(FVec3::splat(0.0).try_normalize().is_none());
+        assert!(FVec3::splat(f32::EPSILON / 2.0).try_normalize().is_none());
+
+        // Scaling by a positive factor shouldn't change the direction
+        assert_abs_diff_eq!((v * 7.5).normalize(), v.normalize());
+        assert_abs_diff_eq!((v * -2.0).normalize(), -v.normalize());
+
+        assert!(FVec3::splat(0.0).normalize().x().is_nan());
+    }
+
+    #[test]
+    fn fvec4_normalize() {
+        let v = FVec4::new(-3.0, 0.5, 12.0, -7.0);
+        assert_abs_diff_eq!(v.normalize().magnitude(), 1.0);
+        assert_abs_diff_eq!(v.try_normalize().unwrap().magnitude(), 1.0);
+
+        assert!(FVec4::splat(0.0).try_normalize().is_none());
+        assert!(FVec4::splat(f32::EPSILON / 4.0).try_normalize().is_none());
+
+        // Scaling by a positive factor shouldn't change the direction
+        assert_abs_diff_eq!((v * 7.5).normalize(), v.normalize());
+        assert_abs_diff_eq!((v * -2.0).normalize(), -v.normalize());
+
+        assert!(FVec4::splat(0.0).normalize().w().is_nan());
+    }
+
+    #[test]
+    fn fvec4_dot() {
+        let l = FVec4::new(1.0, -2.0, 3.0, 0.5);
+        let r = FVec4::new(4.0, 5.0, -6.0, 2.0);
+        // 4 - 10 - 18 + 1
+        assert_abs_diff_eq!(l.dot(r), -23.0);
+        assert_abs_diff_eq!(r.dot(l), -23.0);
+    }
+
+    #[test]
+    fn fvec3_dot() {
+        let l = FVec3::new(1.0, -2.0, 3.0);
+        let r = FVec3::new(4.0, 5.0, -6.0);
+        assert_abs_diff_eq!(l.dot(r), -24.0);
+
+        // The unused `w` component should never contribute to the result
+        let garbage_l: FVec3 = FVec(unsafe { citro3d_sys::FVec4_New(1.0, -2.0, 3.0, 1234.5) });
+        let garbage_r: FVec3 = FVec(unsafe { citro3d_sys::FVec4_New(4.0, 5.0, -6.0, -99.0) });
+        assert_abs_diff_eq!(garbage_l.dot(garbage_r), -24.0);
+    }
+
+    #[test]
+    fn fvec4_dot3() {
+        // The plane x + y = 2, facing away from the origin
+        let normal = FVec3::new(1.0, 1.0, 0.0).normalize();
+        let plane = normal.extend(-normal.dot(FVec3::new(1.0, 1.0, 0.0)));
+
+        let outside = FVec3::new(2.0, 2.0, 7.0);
+        let inside = FVec3::new(0.0, 0.0, -3.0);
+        let on_plane = FVec3::new(2.0, 0.0, 1.0);
+
+        assert!(plane.dot3(outside) > 0.0);
+        assert!(plane.dot3(inside) < 0.0);
+        assert_abs_diff_eq!(plane.dot3(on_plane), 0.0, epsilon = 1.0e-6);
+        assert_abs_diff_eq!(plane.dot3(inside), -(2.0_f32.sqrt()), epsilon = 1.0e-6);
+
+        // Directions aren't affected by the plane's offset
+        assert_abs_diff_eq!(plane.dot3_dir(outside), normal.dot(outside));
+        assert_abs_diff_eq!(plane.dot3_dir(FVec3::UNIT_Z), 0.0);
+    }
+
+    #[test]
+    fn dot_orthogonal_and_self() {
+        let x = FVec3::new(1.0, 0.0, 0.0);
+        let y = FVec3::new(0.0, 1.0, 0.0);
+        assert_abs_diff_eq!(x.dot(y), 0.0);
+        assert_abs_diff_eq!(x.dot(x.cross(y)), 0.0);
+
+        let v = FVec3::new(1.0, -2.0, 2.0);
+        assert_abs_diff_eq!(v.dot(v), v.magnitude() * v.magnitude());
+
+        let x = FVec4::new(1.0, 0.0, 0.0, 0.0);
+        let w = FVec4::new(0.0, 0.0, 0.0, 1.0);
+        assert_abs_diff_eq!(x.dot(w), 0.0);
+
+        let v = FVec4::new(1.0, -2.0, 2.0, 4.0);
+        assert_abs_diff_eq!(v.dot(v), v.magnitude() * v.magnitude());
+    }
 }
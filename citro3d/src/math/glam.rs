@@ -0,0 +1,109 @@
+//! Conversions to and from [`glam`] types.
+//!
+//! Note that `glam` matrices are column-major, unlike [`Matrix`](super::Matrix),
+//! so matrix conversions transpose the underlying data. The resulting matrices
+//! represent the same transformation either way.
+
+use super::{FQuat, FVec3, FVec4, Matrix4};
+
+#[doc(cfg(feature = "glam"))]
+impl From<glam::Vec3> for FVec3 {
+    fn from(v: glam::Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+#[doc(cfg(feature = "glam"))]
+impl From<FVec3> for glam::Vec3 {
+    fn from(v: FVec3) -> Self {
+        Self::new(v.x(), v.y(), v.z())
+    }
+}
+
+#[doc(cfg(feature = "glam"))]
+impl From<glam::Vec4> for FVec4 {
+    fn from(v: glam::Vec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+#[doc(cfg(feature = "glam"))]
+impl From<FVec4> for glam::Vec4 {
+    fn from(v: FVec4) -> Self {
+        Self::new(v.x(), v.y(), v.z(), v.w())
+    }
+}
+
+#[doc(cfg(feature = "glam"))]
+impl From<glam::Quat> for FQuat {
+    fn from(q: glam::Quat) -> Self {
+        Self::new(q.x, q.y, q.z, q.w)
+    }
+}
+
+#[doc(cfg(feature = "glam"))]
+impl From<FQuat> for glam::Quat {
+    fn from(q: FQuat) -> Self {
+        let ijkr = unsafe { q.0.__bindgen_anon_2 };
+        Self::from_xyzw(ijkr.i, ijkr.j, ijkr.k, ijkr.r)
+    }
+}
+
+#[doc(cfg(feature = "glam"))]
+impl From<glam::Mat4> for Matrix4 {
+    fn from(m: glam::Mat4) -> Self {
+        // The columns of the transpose are the rows of the original
+        Self::from_rows(m.transpose().to_cols_array_2d())
+    }
+}
+
+#[doc(cfg(feature = "glam"))]
+impl From<Matrix4> for glam::Mat4 {
+    fn from(m: Matrix4) -> Self {
+        Self::from_cols_array_2d(&m.to_rows()).transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_4;
+
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn vectors() {
+        let v = glam::Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(FVec3::from(v), FVec3::new(1.0, 2.0, 3.0));
+        assert_eq!(glam::Vec3::from(FVec3::from(v)), v);
+
+        let v = glam::Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(FVec4::from(v), FVec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(glam::Vec4::from(FVec4::from(v)), v);
+    }
+
+    #[test]
+    fn quaternion() {
+        let q = glam::Quat::from_rotation_z(FRAC_PI_4);
+        let fquat = FQuat::from(q);
+        assert_abs_diff_eq!(fquat, FQuat::identity().rotate_z(FRAC_PI_4));
+        assert_eq!(glam::Quat::from(fquat), q);
+    }
+
+    #[test]
+    fn matrix() {
+        let glam_mtx = glam::Mat4::from_translation(glam::Vec3::new(1.0, 2.0, 3.0));
+        let mtx = Matrix4::from(glam_mtx);
+
+        let mut expected = Matrix4::identity();
+        expected.translate(1.0, 2.0, 3.0);
+        assert_eq!(mtx, expected);
+        assert_eq!(glam::Mat4::from(mtx), glam_mtx);
+
+        let v = glam::Vec4::new(4.0, 5.0, 6.0, 1.0);
+        let translated = mtx * FVec4::from(v);
+        assert_eq!(translated, FVec4::new(5.0, 7.0, 9.0, 1.0));
+        assert_eq!(glam::Vec4::from(translated), glam_mtx * v);
+    }
+}
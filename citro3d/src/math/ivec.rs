@@ -0,0 +1,92 @@
+//! Packed integer vectors.
+
+use std::fmt;
+
+/// A 4-vector of `u8`s, packed into a single `u32`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+#[doc(alias = "C3D_IVec")]
+pub struct IVec(pub(crate) citro3d_sys::C3D_IVec);
+
+// SAFETY: C3D_IVec is just a `u32`
+unsafe impl bytemuck::Zeroable for IVec {}
+unsafe impl bytemuck::Pod for IVec {}
+
+impl fmt::Debug for IVec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IVec")
+            .field(&self.x())
+            .field(&self.y())
+            .field(&self.z())
+            .field(&self.w())
+            .finish()
+    }
+}
+
+impl IVec {
+    /// Create a new [`IVec`] from its components.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::IVec;
+    /// let v = IVec::new(1, 2, 3, 4);
+    /// assert_eq!((v.x(), v.y(), v.z(), v.w()), (1, 2, 3, 4));
+    /// ```
+    #[doc(alias = "IVec_Pack")]
+    pub fn new(x: u8, y: u8, z: u8, w: u8) -> Self {
+        Self(unsafe { citro3d_sys::IVec_Pack(x, y, z, w) })
+    }
+
+    /// The vector's `x` component.
+    pub fn x(self) -> u8 {
+        self.component(0)
+    }
+
+    /// The vector's `y` component.
+    pub fn y(self) -> u8 {
+        self.component(1)
+    }
+
+    /// The vector's `z` component.
+    pub fn z(self) -> u8 {
+        self.component(2)
+    }
+
+    /// The vector's `w` component.
+    pub fn w(self) -> u8 {
+        self.component(3)
+    }
+
+    /// Components are packed starting from the least significant byte, i.e.
+    /// `x` is the lowest byte and `w` is the highest.
+    fn component(self, index: u32) -> u8 {
+        (self.0 >> (index * 8)) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pack_unpack() {
+        let v = IVec::new(1, 2, 3, 4);
+        assert_eq!((v.x(), v.y(), v.z(), v.w()), (1, 2, 3, 4));
+
+        let v = IVec::new(0, u8::MAX, 0x80, 0x7F);
+        assert_eq!((v.x(), v.y(), v.z(), v.w()), (0, u8::MAX, 0x80, 0x7F));
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", IVec::new(1, 2, 3, 4)), "IVec(1, 2, 3, 4)");
+    }
+
+    #[test]
+    fn bytemuck() {
+        let v = IVec::new(1, 2, 3, 4);
+        assert_eq!(bytemuck::cast::<_, u32>(v), 0x04030201);
+        assert_eq!(bytemuck::bytes_of(&v), [1, 2, 3, 4]);
+    }
+}
@@ -1,17 +1,38 @@
+use std::fmt;
 use std::mem::MaybeUninit;
 
 pub use private::Matrix;
 
-use super::{CoordinateOrientation, FVec3};
+use super::{CoordinateOrientation, FQuat, FVec, FVec3, FVec4};
 
 mod private {
     use std::fmt;
 
     /// An `M`x`N` row-major matrix of `f32`s.
+    ///
+    /// Matrices implement [`bytemuck::NoUninit`], so they can be copied
+    /// directly into uniform or vertex data. Note that they are always stored as 4x4, with
+    /// each row in WZYX order like [`FVec`](crate::math::FVec), so casting a
+    /// matrix to raw `f32`s or bytes will see the columns of each row reversed.
     #[doc(alias = "C3D_Mtx")]
-    #[derive(Clone)]
+    #[derive(Clone, Copy)]
+    #[repr(transparent)]
     pub struct Matrix<const M: usize, const N: usize>(citro3d_sys::C3D_Mtx);
 
+    // SAFETY: C3D_Mtx is a union of `f32` arrays, so it has no padding.
+    // Matrices aren't `Pod` though: casting arbitrary data to a matrix would
+    // bypass both `new`'s size checks and the padding `from_raw` requires.
+    const _: () = assert!(std::mem::size_of::<citro3d_sys::C3D_Mtx>() == 16 * 4);
+    unsafe impl<const M: usize, const N: usize> bytemuck::NoUninit for Matrix<M, N> {}
+
+    // SAFETY: all zeroes is a valid matrix of any supported size, padding included.
+    macro_rules! impl_zeroable {
+        ($($m:literal x $n:literal),*) => {
+            $(unsafe impl bytemuck::Zeroable for Matrix<$m, $n> {})*
+        };
+    }
+    impl_zeroable!(3 x 1, 3 x 2, 3 x 3, 3 x 4, 4 x 1, 4 x 2, 4 x 3, 4 x 4);
+
     impl<const M: usize, const N: usize> Matrix<M, N> {
         const ROW_SIZE: () = assert!(M == 3 || M == 4);
         const COLUMN_SIZE: () = assert!(N > 0 && N <= 4);
@@ -19,7 +40,8 @@ mod private {
         // This constructor validates, at compile time, that the
         // constructed matrix is 3xN or 4xN matrix, where 0 < N ≤ 4.
         // We put this struct in a submodule to enforce that nothing creates
-        // a Matrix without calling this constructor.
+        // a Matrix without calling this constructor (`bytemuck::Zeroable` is
+        // only implemented for the sizes it accepts).
         #[allow(clippy::let_unit_value)]
         pub(crate) fn new(value: citro3d_sys::C3D_Mtx) -> Self {
             let () = Self::ROW_SIZE;
@@ -27,11 +49,39 @@ mod private {
             Self(value)
         }
 
-        pub(crate) fn as_raw(&self) -> *const citro3d_sys::C3D_Mtx {
+        /// Create a matrix from a raw [`C3D_Mtx`](citro3d_sys::C3D_Mtx), e.g.
+        /// one produced by a `citro3d_sys` function this crate doesn't wrap.
+        ///
+        /// # Safety
+        ///
+        /// Every element of `value`'s 4x4 storage outside the `M`x`N` matrix
+        /// must be zero, except element (3, 3) of a 3xN matrix, which may hold
+        /// any value (e.g. the 1 of an identity matrix). `citro3d` always
+        /// operates on the full storage, and the rest of this crate relies on
+        /// that padding never leaking into the elements of results.
+        ///
+        /// # Example
+        /// ```
+        /// # let _runner = test_runner::GdbRunner::default();
+        /// # use citro3d::math::Matrix4;
+        /// let raw = Matrix4::identity().into_raw();
+        /// // SAFETY: a 4x4 matrix has no unused rows or columns
+        /// let mtx = unsafe { Matrix4::from_raw(raw) };
+        /// assert_eq!(mtx, Matrix4::identity());
+        /// ```
+        pub unsafe fn from_raw(value: citro3d_sys::C3D_Mtx) -> Self {
+            Self::new(value)
+        }
+
+        /// Get a pointer to the underlying [`C3D_Mtx`](citro3d_sys::C3D_Mtx),
+        /// for passing to `citro3d_sys` functions.
+        pub fn as_raw(&self) -> *const citro3d_sys::C3D_Mtx {
             &self.0
         }
 
-        pub(crate) fn into_raw(self) -> citro3d_sys::C3D_Mtx {
+        /// Consume the matrix, returning the underlying
+        /// [`C3D_Mtx`](citro3d_sys::C3D_Mtx).
+        pub fn into_raw(self) -> citro3d_sys::C3D_Mtx {
             self.0
         }
 
@@ -60,15 +110,8 @@ mod private {
 
     impl<const M: usize, const N: usize> fmt::Debug for Matrix<M, N> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            let inner = self.as_rows().map(|mut row| {
-                // Rows are stored in WZYX order which is opposite of how most people
-                // probably expect, so reverse each row in-place for debug printing
-                row.reverse();
-                row
-            });
-
             let type_name = std::any::type_name::<Self>().split("::").last().unwrap();
-            f.debug_tuple(type_name).field(&inner).finish()
+            f.debug_tuple(type_name).field(&self.to_rows()).finish()
         }
     }
 }
@@ -80,9 +123,11 @@ pub type Matrix4 = Matrix<4, 4>;
 
 impl<const M: usize, const N: usize> Matrix<M, N> {
     /// Construct the zero matrix.
+    ///
+    /// Note that this is _not_ the [`Default`] for square matrices, which is
+    /// the [`identity`](Self::identity) matrix instead.
     #[doc(alias = "Mtx_Zeros")]
     pub fn zero() -> Self {
-        // TODO: should this also be Default::default()?
         let mut out = MaybeUninit::uninit();
         unsafe {
             citro3d_sys::Mtx_Zeros(out.as_mut_ptr());
@@ -90,6 +135,147 @@ impl<const M: usize, const N: usize> Matrix<M, N> {
         }
     }
 
+    /// Construct a matrix from an array of rows, in the same order returned by
+    /// [`to_rows`](Self::to_rows). Any elements of the underlying 4x4 storage
+    /// outside the `M`x`N` matrix are set to zero.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix3;
+    /// let rows = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+    /// let mtx = Matrix3::from_rows(rows);
+    /// assert_eq!(mtx[(0, 2)], 3.0);
+    /// assert_eq!(mtx.to_rows(), rows);
+    /// ```
+    pub fn from_rows(rows: [[f32; N]; M]) -> Self {
+        let mut out = Self::zero();
+        for (i, row) in rows.into_iter().enumerate() {
+            for (j, value) in row.into_iter().enumerate() {
+                out[(i, j)] = value;
+            }
+        }
+        out
+    }
+
+    /// Get the element at the given `row` and `col`umn.
+    /// Equivalent to `self[(row, col)]`.
+    ///
+    /// # Panics
+    ///
+    /// If `row` or `col` is out of bounds for the matrix's size.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix4;
+    /// let mut view = Matrix4::identity();
+    /// view.translate(1.0, 2.0, 3.0);
+    /// assert_eq!(view.get(2, 3), 3.0);
+    /// ```
+    pub fn get(&self, row: usize, col: usize) -> f32 {
+        self[(row, col)]
+    }
+
+    /// Set the element at the given `row` and `col`umn.
+    /// Equivalent to `self[(row, col)] = value`.
+    ///
+    /// # Panics
+    ///
+    /// If `row` or `col` is out of bounds for the matrix's size.
+    pub fn set(&mut self, row: usize, col: usize, value: f32) {
+        self[(row, col)] = value;
+    }
+
+    /// Whether all of the matrix's elements are finite, i.e. neither infinite
+    /// nor NaN.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix4;
+    /// let mut mtx = Matrix4::identity();
+    /// assert!(mtx.is_finite());
+    /// mtx[(0, 3)] = f32::NAN;
+    /// assert!(!mtx.is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.as_rows().iter().flatten().all(|x| x.is_finite())
+    }
+
+    /// Whether every element of the matrix is within `epsilon` of zero.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix4;
+    /// let mut mtx = Matrix4::zero();
+    /// mtx[(1, 2)] = 1.0e-6;
+    /// assert!(mtx.is_zero(1.0e-5));
+    /// assert!(!mtx.is_zero(0.0));
+    /// ```
+    pub fn is_zero(&self, epsilon: f32) -> bool {
+        self.as_rows().iter().flatten().all(|x| x.abs() <= epsilon)
+    }
+
+    /// Construct a matrix from an array of columns. This is equivalent to
+    /// [`from_rows`](Self::from_rows) followed by a transpose.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix4;
+    /// let mtx = Matrix4::from_cols([
+    ///     [1.0, 0.0, 0.0, 0.0],
+    ///     [0.0, 1.0, 0.0, 0.0],
+    ///     [0.0, 0.0, 1.0, 0.0],
+    ///     [1.0, 2.0, 3.0, 1.0],
+    /// ]);
+    ///
+    /// let mut expected = Matrix4::identity();
+    /// expected.translate(1.0, 2.0, 3.0);
+    /// assert_eq!(mtx, expected);
+    /// ```
+    pub fn from_cols(cols: [[f32; M]; N]) -> Self {
+        let mut out = Self::zero();
+        for (j, col) in cols.into_iter().enumerate() {
+            for (i, value) in col.into_iter().enumerate() {
+                out[(i, j)] = value;
+            }
+        }
+        out
+    }
+
+    /// Copy the matrix's elements out as an array of rows (i.e. in row-major
+    /// order). Each row is ordered from the first column to the last, so for
+    /// a [`Matrix4`] each row is in XYZW order.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix4;
+    /// let mut mtx = Matrix4::identity();
+    /// mtx.translate(1.0, 2.0, 3.0);
+    /// assert_eq!(
+    ///     mtx.to_rows(),
+    ///     [
+    ///         [1.0, 0.0, 0.0, 1.0],
+    ///         [0.0, 1.0, 0.0, 2.0],
+    ///         [0.0, 0.0, 1.0, 3.0],
+    ///         [0.0, 0.0, 0.0, 1.0],
+    ///     ]
+    /// );
+    /// ```
+    #[doc(alias = "rows")]
+    pub fn to_rows(&self) -> [[f32; N]; M] {
+        self.as_rows().map(|mut row| {
+            // Rows are stored in WZYX order which is opposite of how most people
+            // probably expect, so reverse each row in-place
+            row.reverse();
+            row
+        })
+    }
+
     /// Transpose the matrix, swapping rows and columns.
     #[doc(alias = "Mtx_Transpose")]
     pub fn transpose(mut self) -> Matrix<N, M> {
@@ -99,49 +285,111 @@ impl<const M: usize, const N: usize> Matrix<M, N> {
         Matrix::new(self.into_raw())
     }
 
+    /// Transpose a copy of the matrix, leaving the original untouched. See
+    /// [`transpose`](Self::transpose).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix;
+    /// let mtx = Matrix::<4, 3>::from_rows([
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 5.0, 6.0],
+    ///     [7.0, 8.0, 9.0],
+    ///     [10.0, 11.0, 12.0],
+    /// ]);
+    /// let transposed: Matrix<3, 4> = mtx.transposed();
+    /// assert_eq!(transposed[(2, 1)], mtx[(1, 2)]);
+    /// ```
+    #[doc(alias = "Mtx_Transpose")]
+    pub fn transposed(&self) -> Matrix<N, M> {
+        self.transpose()
+    }
+
     // region: Matrix transformations
-    //
-    // NOTE: the `bRightSide` arg common to many of these APIs flips the order of
-    // operations so that a transformation occurs as self(T) instead of T(self).
-    // For now I'm not sure if that's a common use case, but if needed we could
-    // probably have some kinda wrapper type that does transformations in the
-    // opposite order, or an enum arg for these APIs or something.
 
     /// Translate a transformation matrix by the given amounts in the X, Y, and Z
-    /// directions.
+    /// directions. The translation is applied after any existing transformation,
+    /// see [`TransformOrder::World`].
     #[doc(alias = "Mtx_Translate")]
     pub fn translate(&mut self, x: f32, y: f32, z: f32) {
-        unsafe { citro3d_sys::Mtx_Translate(self.as_mut(), x, y, z, false) }
+        self.translate_with_order(x, y, z, TransformOrder::World);
+    }
+
+    /// Translate a transformation matrix by the given amounts in the X, Y, and Z
+    /// directions, in the given [`TransformOrder`].
+    #[doc(alias = "Mtx_Translate")]
+    pub fn translate_with_order(&mut self, x: f32, y: f32, z: f32, order: TransformOrder) {
+        unsafe { citro3d_sys::Mtx_Translate(self.as_mut(), x, y, z, order.is_right_side()) }
     }
 
     /// Scale a transformation matrix by the given amounts in the X, Y, and Z directions.
+    ///
+    /// Unlike the other transformations, the scale is always applied _before_
+    /// any existing transformation, i.e. in [`TransformOrder::Local`] space.
     #[doc(alias = "Mtx_Scale")]
     pub fn scale(&mut self, x: f32, y: f32, z: f32) {
         unsafe { citro3d_sys::Mtx_Scale(self.as_mut(), x, y, z) }
     }
 
-    /// Rotate a transformation matrix by the given angle around the given axis.
+    /// Rotate a transformation matrix by the given angle (in radians) around the given axis.
+    /// The rotation is applied after any existing transformation, see
+    /// [`TransformOrder::World`].
     #[doc(alias = "Mtx_Rotate")]
     pub fn rotate(&mut self, axis: FVec3, angle: f32) {
-        unsafe { citro3d_sys::Mtx_Rotate(self.as_mut(), axis.0, angle, false) }
+        self.rotate_with_order(axis, angle, TransformOrder::World);
+    }
+
+    /// Rotate a transformation matrix by the given angle (in radians) around the given axis,
+    /// in the given [`TransformOrder`].
+    #[doc(alias = "Mtx_Rotate")]
+    pub fn rotate_with_order(&mut self, axis: FVec3, angle: f32, order: TransformOrder) {
+        unsafe { citro3d_sys::Mtx_Rotate(self.as_mut(), axis.0, angle, order.is_right_side()) }
     }
 
-    /// Rotate a transformation matrix by the given angle around the X axis.
+    /// Rotate a transformation matrix by the given angle (in radians) around the X axis.
+    /// The rotation is applied after any existing transformation, see
+    /// [`TransformOrder::World`].
     #[doc(alias = "Mtx_RotateX")]
     pub fn rotate_x(&mut self, angle: f32) {
-        unsafe { citro3d_sys::Mtx_RotateX(self.as_mut(), angle, false) }
+        self.rotate_x_with_order(angle, TransformOrder::World);
+    }
+
+    /// Rotate a transformation matrix by the given angle (in radians) around the X axis,
+    /// in the given [`TransformOrder`].
+    #[doc(alias = "Mtx_RotateX")]
+    pub fn rotate_x_with_order(&mut self, angle: f32, order: TransformOrder) {
+        unsafe { citro3d_sys::Mtx_RotateX(self.as_mut(), angle, order.is_right_side()) }
     }
 
-    /// Rotate a transformation matrix by the given angle around the Y axis.
+    /// Rotate a transformation matrix by the given angle (in radians) around the Y axis.
+    /// The rotation is applied after any existing transformation, see
+    /// [`TransformOrder::World`].
     #[doc(alias = "Mtx_RotateY")]
     pub fn rotate_y(&mut self, angle: f32) {
-        unsafe { citro3d_sys::Mtx_RotateY(self.as_mut(), angle, false) }
+        self.rotate_y_with_order(angle, TransformOrder::World);
     }
 
-    /// Rotate a transformation matrix by the given angle around the Z axis.
+    /// Rotate a transformation matrix by the given angle (in radians) around the Y axis,
+    /// in the given [`TransformOrder`].
+    #[doc(alias = "Mtx_RotateY")]
+    pub fn rotate_y_with_order(&mut self, angle: f32, order: TransformOrder) {
+        unsafe { citro3d_sys::Mtx_RotateY(self.as_mut(), angle, order.is_right_side()) }
+    }
+
+    /// Rotate a transformation matrix by the given angle (in radians) around the Z axis.
+    /// The rotation is applied after any existing transformation, see
+    /// [`TransformOrder::World`].
     #[doc(alias = "Mtx_RotateZ")]
     pub fn rotate_z(&mut self, angle: f32) {
-        unsafe { citro3d_sys::Mtx_RotateZ(self.as_mut(), angle, false) }
+        self.rotate_z_with_order(angle, TransformOrder::World);
+    }
+
+    /// Rotate a transformation matrix by the given angle (in radians) around the Z axis,
+    /// in the given [`TransformOrder`].
+    #[doc(alias = "Mtx_RotateZ")]
+    pub fn rotate_z_with_order(&mut self, angle: f32, order: TransformOrder) {
+        unsafe { citro3d_sys::Mtx_RotateZ(self.as_mut(), angle, order.is_right_side()) }
     }
 
     // endregion
@@ -152,9 +400,25 @@ impl<const N: usize> Matrix<N, N> {
     ///
     /// # Errors
     ///
-    /// If the matrix has no inverse, it will be returned unchanged as an [`Err`].
+    /// If the matrix has no inverse (i.e. the absolute value of its
+    /// [`determinant`](Self::determinant) is less than [`f32::EPSILON`]), it
+    /// will be returned unchanged as an [`Err`].
     #[doc(alias = "Mtx_Inverse")]
     pub fn inverse(mut self) -> Result<Self, Self> {
+        if N == 3 {
+            // Mtx_Inverse works on the whole 4x4 storage, so the unused row
+            // and column need to be the same as the identity matrix's, or a
+            // zero-padded 3x3 matrix would always be considered singular.
+            unsafe {
+                let rows = &mut (*self.as_mut()).r;
+                for row in &mut rows[..3] {
+                    // Rows are stored in WZYX order, so W is the first element
+                    row.c[0] = 0.0;
+                }
+                rows[3].c = [1.0, 0.0, 0.0, 0.0];
+            }
+        }
+
         let determinant = unsafe { citro3d_sys::Mtx_Inverse(self.as_mut()) };
         if determinant == 0.0 {
             Err(self)
@@ -163,6 +427,187 @@ impl<const N: usize> Matrix<N, N> {
         }
     }
 
+    /// Find the inverse of the matrix without consuming it, or `None` if the
+    /// matrix has no inverse. See [`inverse`](Self::inverse).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix4;
+    /// # use approx::assert_abs_diff_eq;
+    /// let mtx = Matrix4::diagonal(2.0, 4.0, 0.5, 1.0);
+    /// let inverse = mtx.inversed().unwrap();
+    /// assert_abs_diff_eq!(&mtx * &inverse, Matrix4::identity());
+    ///
+    /// assert_eq!(Matrix4::zero().inversed(), None);
+    /// ```
+    #[doc(alias = "Mtx_Inverse")]
+    pub fn inversed(&self) -> Option<Self> {
+        self.inverse().ok()
+    }
+
+    /// Find the transpose of the matrix's inverse, or `None` if the matrix has
+    /// no inverse. This is typically used to transform normal vectors, which
+    /// would otherwise be skewed by a non-uniform scale.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, FVec4, Matrix4};
+    /// # use approx::assert_abs_diff_eq;
+    /// let model = Matrix4::from_scale(FVec3::new(1.0, 4.0, 1.0));
+    /// let normal_matrix = model.inverse_transpose().unwrap();
+    ///
+    /// // A surface sloping at 45°, and its normal vector
+    /// let tangent = FVec4::new(1.0, 1.0, 0.0, 0.0);
+    /// let normal = FVec4::new(1.0, -1.0, 0.0, 0.0);
+    ///
+    /// // The transformed normal is still perpendicular to the transformed surface
+    /// let tangent = &model * tangent;
+    /// let normal = &normal_matrix * normal;
+    /// assert_abs_diff_eq!(tangent.dot(normal), 0.0);
+    /// ```
+    pub fn inverse_transpose(&self) -> Option<Self> {
+        self.inversed().map(Self::transpose)
+    }
+
+    /// Calculate the determinant of the matrix. A negative determinant means
+    /// the matrix flips the winding order of anything it transforms, and a
+    /// zero determinant means the matrix has no inverse.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix4;
+    /// # use approx::assert_abs_diff_eq;
+    /// let mtx = Matrix4::diagonal(2.0, 3.0, -4.0, 1.0);
+    /// assert_abs_diff_eq!(mtx.determinant(), -24.0);
+    /// ```
+    pub fn determinant(&self) -> f32 {
+        if N == 3 {
+            // Mtx_Inverse works on the whole 4x4 storage, which isn't what we
+            // want for a 3x3 matrix, so just use the rule of Sarrus instead.
+            let m = |row, col| self[(row, col)];
+            m(0, 0) * (m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1))
+                - m(0, 1) * (m(1, 0) * m(2, 2) - m(1, 2) * m(2, 0))
+                + m(0, 2) * (m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0))
+        } else {
+            // Mtx_Inverse also returns the determinant, but clamps anything
+            // smaller than f32::EPSILON to zero. Instead, expand by cofactors,
+            // pairing 2x2 sub-determinants of the top and bottom two rows.
+            let m = |row, col| self[(row, col)];
+            let s0 = m(0, 0) * m(1, 1) - m(1, 0) * m(0, 1);
+            let s1 = m(0, 0) * m(1, 2) - m(1, 0) * m(0, 2);
+            let s2 = m(0, 0) * m(1, 3) - m(1, 0) * m(0, 3);
+            let s3 = m(0, 1) * m(1, 2) - m(1, 1) * m(0, 2);
+            let s4 = m(0, 1) * m(1, 3) - m(1, 1) * m(0, 3);
+            let s5 = m(0, 2) * m(1, 3) - m(1, 2) * m(0, 3);
+
+            let c5 = m(2, 2) * m(3, 3) - m(3, 2) * m(2, 3);
+            let c4 = m(2, 1) * m(3, 3) - m(3, 1) * m(2, 3);
+            let c3 = m(2, 1) * m(3, 2) - m(3, 1) * m(2, 2);
+            let c2 = m(2, 0) * m(3, 3) - m(3, 0) * m(2, 3);
+            let c1 = m(2, 0) * m(3, 2) - m(3, 0) * m(2, 2);
+            let c0 = m(2, 0) * m(3, 1) - m(3, 0) * m(2, 1);
+
+            s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+        }
+    }
+
+    /// Whether the matrix has an inverse. Unlike [`inverse`](Self::inverse),
+    /// this doesn't consume the matrix.
+    ///
+    /// Like `inverse`, this treats any matrix whose [`determinant`](Self::determinant)
+    /// is smaller than [`f32::EPSILON`] in absolute value as singular, so e.g. a
+    /// uniform scale of `1e-3` has no inverse even though its determinant isn't
+    /// exactly zero.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix4;
+    /// assert!(Matrix4::identity().is_invertible());
+    /// assert!(!Matrix4::zero().is_invertible());
+    /// ```
+    pub fn is_invertible(&self) -> bool {
+        self.determinant().abs() >= f32::EPSILON
+    }
+
+    /// Re-orthonormalize the rotation part of the matrix (the upper-left 3x3)
+    /// using the Gram-Schmidt process, so that its basis vectors are
+    /// perpendicular and unit length. Any other elements, e.g. the translation
+    /// of a [`Matrix4`], are left unchanged.
+    ///
+    /// This is useful to correct for the rounding error that accumulates when
+    /// repeatedly rotating a matrix. Note that this assumes the matrix doesn't
+    /// intentionally have any scale, since it will be removed.
+    ///
+    /// The X axis (the first column) keeps its direction, and the Y and Z axes
+    /// are adjusted to be perpendicular to it, in that order.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, Matrix4, TransformOrder};
+    /// # use approx::assert_abs_diff_eq;
+    /// let mut mtx = Matrix4::from_translation(FVec3::new(1.0, 2.0, 3.0));
+    /// for _ in 0..1000 {
+    ///     mtx.rotate_x_with_order(0.01, TransformOrder::Local);
+    /// }
+    ///
+    /// mtx.orthonormalize();
+    /// assert_abs_diff_eq!(mtx.upper_3x3().determinant(), 1.0);
+    /// assert_eq!(mtx.translation(), FVec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn orthonormalize(&mut self) {
+        let column = |j| FVec3::new(self[(0, j)], self[(1, j)], self[(2, j)]);
+        let (x, y, z) = (column(0), column(1), column(2));
+
+        let x = x.normalize();
+        let y = (y - x * x.dot(y)).normalize();
+        let z = (z - x * x.dot(z) - y * y.dot(z)).normalize();
+
+        for (j, axis) in [x, y, z].into_iter().enumerate() {
+            for i in 0..3 {
+                self[(i, j)] = axis[i];
+            }
+        }
+    }
+
+    /// Whether every element of the matrix is within `epsilon` of the
+    /// [`identity`](Self::identity) matrix's.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix4;
+    /// let mut mtx = Matrix4::identity();
+    /// mtx.rotate_x(1.0e-6);
+    /// assert!(mtx.is_identity(1.0e-5));
+    /// assert!(!Matrix4::zero().is_identity(1.0e-5));
+    /// ```
+    pub fn is_identity(&self, epsilon: f32) -> bool {
+        self.to_rows().iter().enumerate().all(|(i, row)| {
+            row.iter().enumerate().all(|(j, x)| {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                (x - expected).abs() <= epsilon
+            })
+        })
+    }
+
+    /// Calculate the trace of the matrix, i.e. the sum of its diagonal elements.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix3;
+    /// let mtx = Matrix3::diagonal(2.0, 3.0, -4.0);
+    /// assert_eq!(mtx.trace(), 1.0);
+    /// ```
+    pub fn trace(&self) -> f32 {
+        (0..N).map(|i| self[(i, i)]).sum()
+    }
+
     /// Construct the identity matrix.
     #[doc(alias = "Mtx_Identity")]
     pub fn identity() -> Self {
@@ -174,6 +619,108 @@ impl<const N: usize> Matrix<N, N> {
     }
 }
 
+impl<const M: usize, const N: usize> fmt::Display for Matrix<M, N> {
+    /// Print each row of the matrix on its own line, like `(1, 2, 3)`, with
+    /// the columns aligned. Any formatting options (e.g. `{:.2}`) are applied
+    /// to each element.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self.to_rows().map(|row| {
+            row.map(|x| match f.precision() {
+                Some(precision) => format!("{x:.precision$}"),
+                None => format!("{x}"),
+            })
+        });
+
+        let mut widths = [f.width().unwrap_or(0); N];
+        for row in &rows {
+            for (width, element) in widths.iter_mut().zip(row) {
+                *width = (*width).max(element.chars().count());
+            }
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            f.write_str("(")?;
+            for (j, (element, width)) in row.iter().zip(widths).enumerate() {
+                if j > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{element:>width$}")?;
+            }
+            f.write_str(")")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The order in which a transformation (e.g. [`Matrix::translate_with_order`])
+/// is combined with a matrix's existing transformation.
+///
+/// # Example
+/// ```
+/// # let _runner = test_runner::GdbRunner::default();
+/// # use std::f32::consts::FRAC_PI_2;
+/// # use citro3d::math::{FVec4, Matrix4, TransformOrder};
+/// # use approx::assert_abs_diff_eq;
+/// // An object 5 units along the X axis
+/// let mut model = Matrix4::identity();
+/// model.translate(5.0, 0.0, 0.0);
+///
+/// // Spinning in place keeps the object where it is...
+/// let mut local = model.clone();
+/// local.rotate_z_with_order(FRAC_PI_2, TransformOrder::Local);
+/// let origin = FVec4::new(0.0, 0.0, 0.0, 1.0);
+/// assert_abs_diff_eq!(&local * origin, FVec4::new(5.0, 0.0, 0.0, 1.0));
+///
+/// // ...but rotating around the world origin moves it
+/// let mut world = model.clone();
+/// world.rotate_z_with_order(FRAC_PI_2, TransformOrder::World);
+/// assert_abs_diff_eq!(&world * origin, FVec4::new(0.0, 5.0, 0.0, 1.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[doc(alias = "bRightSide")]
+pub enum TransformOrder {
+    /// Apply the new transformation _after_ the existing one, i.e. in world
+    /// space, relative to the origin of the parent coordinate system.
+    /// Equivalent to `T * self`.
+    World,
+    /// Apply the new transformation _before_ the existing one, i.e. in the
+    /// local space of the object being transformed. Equivalent to `self * T`.
+    Local,
+}
+
+impl TransformOrder {
+    fn is_right_side(self) -> bool {
+        matches!(self, Self::Local)
+    }
+}
+
+impl Default for TransformOrder {
+    /// [`World`](Self::World) is the default, since it's how transformations
+    /// are ordered by e.g. [`Matrix::translate`].
+    fn default() -> Self {
+        Self::World
+    }
+}
+
+impl<const N: usize> Default for Matrix<N, N> {
+    /// The identity matrix, since a default transformation should usually
+    /// leave things unchanged. Use [`Matrix::zero`] for the zero matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix4;
+    /// assert_eq!(Matrix4::default(), Matrix4::identity());
+    /// ```
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
 impl Matrix3 {
     /// Construct a 3x3 matrix with the given values on the diagonal.
     #[doc(alias = "Mtx_Diagonal")]
@@ -184,6 +731,27 @@ impl Matrix3 {
             Self::new(out.assume_init())
         }
     }
+
+    /// Embed the matrix into the upper-left of a 4x4 matrix, with the
+    /// remaining elements taken from the [identity](Matrix4::identity) matrix.
+    /// This is the inverse of [`Matrix4::upper_3x3`].
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{Matrix3, Matrix4};
+    /// let mtx = Matrix3::diagonal(2.0, 3.0, 4.0);
+    /// assert_eq!(mtx.to_matrix4(), Matrix4::diagonal(2.0, 3.0, 4.0, 1.0));
+    /// ```
+    pub fn to_matrix4(&self) -> Matrix4 {
+        let mut out = Matrix4::identity();
+        for row in 0..3 {
+            for col in 0..3 {
+                out[(row, col)] = self[(row, col)];
+            }
+        }
+        out
+    }
 }
 
 impl Matrix4 {
@@ -197,8 +765,386 @@ impl Matrix4 {
         }
     }
 
+    /// Get the `i`th row of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is out of bounds (`i >= 4`).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec4, Matrix4};
+    /// let mut mtx = Matrix4::identity();
+    /// mtx.translate(1.0, 2.0, 3.0);
+    /// assert_eq!(mtx.row(0), FVec4::new(1.0, 0.0, 0.0, 1.0));
+    /// ```
+    pub fn row(&self, i: usize) -> FVec4 {
+        assert!(i < 4, "row {i} out of bounds for Matrix4");
+        FVec(unsafe { (*self.as_raw()).r[i] })
+    }
+
+    /// Get the `j`th column of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// If `j` is out of bounds (`j >= 4`).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec4, Matrix4};
+    /// let mut mtx = Matrix4::identity();
+    /// mtx.translate(1.0, 2.0, 3.0);
+    /// assert_eq!(mtx.column(3), FVec4::new(1.0, 2.0, 3.0, 1.0));
+    /// ```
+    pub fn column(&self, j: usize) -> FVec4 {
+        assert!(j < 4, "column {j} out of bounds for Matrix4");
+        FVec4::new(self[(0, j)], self[(1, j)], self[(2, j)], self[(3, j)])
+    }
+
+    /// Get the translation component of a transformation matrix, i.e. the
+    /// first three elements of its last column.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, Matrix4};
+    /// let mut mtx = Matrix4::from_scale(FVec3::splat(2.0));
+    /// mtx.translate(1.0, 2.0, 3.0);
+    /// assert_eq!(mtx.translation(), FVec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn translation(&self) -> FVec3 {
+        FVec3::new(self[(0, 3)], self[(1, 3)], self[(2, 3)])
+    }
+
+    /// Transform a point by this matrix, treating it as a homogeneous
+    /// coordinate with `w = 1`. The result is divided by its `w` component
+    /// if that isn't 1, e.g. after applying a projection matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, Matrix4};
+    /// let mtx = Matrix4::from_translation(FVec3::new(1.0, 2.0, 3.0));
+    /// let p = mtx.transform_point(FVec3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(p, FVec3::new(2.0, 3.0, 4.0));
+    /// ```
+    pub fn transform_point(&self, point: FVec3) -> FVec3 {
+        let out = self * point.to_homogeneous();
+        if out.w() == 1.0 {
+            out.truncate()
+        } else {
+            out.project()
+        }
+    }
+
+    /// Transform a direction by this matrix, treating it as a homogeneous
+    /// coordinate with `w = 0`, so that the matrix's translation is ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, Matrix4};
+    /// let mtx = Matrix4::from_translation(FVec3::new(1.0, 2.0, 3.0));
+    /// let v = mtx.transform_vector(FVec3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(v, FVec3::new(1.0, 1.0, 1.0));
+    /// ```
+    pub fn transform_vector(&self, vector: FVec3) -> FVec3 {
+        (self * vector.extend(0.0)).truncate()
+    }
+
+    /// Extract the six planes of the view frustum from a projection (or
+    /// combined projection and view) matrix, in the order left, right, bottom,
+    /// top, near, far. For a projection that is [rotated](super::ScreenOrientation::Rotated)
+    /// for the 3DS screens, left/right and bottom/top refer to the
+    /// framebuffer's axes rather than the screen as the user sees it.
+    ///
+    /// Each plane is a normalized `(a, b, c, d)` with its normal pointing into
+    /// the frustum, so `a * x + b * y + c * z + d` is the signed distance of a
+    /// point from the plane, which is positive on the inside.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use std::f32::consts::FRAC_PI_4;
+    /// # use citro3d::math::{AspectRatio, ClipPlanes, FVec3, Matrix4, Projection};
+    /// let projection = Projection::perspective(
+    ///     FRAC_PI_4,
+    ///     AspectRatio::TopScreen,
+    ///     ClipPlanes {
+    ///         near: 0.1,
+    ///         far: 100.0,
+    ///     },
+    /// );
+    /// let planes = projection.to_matrix().frustum_planes();
+    ///
+    /// let is_visible = |p: FVec3| planes.iter().all(|plane| plane.dot(p.to_homogeneous()) >= 0.0);
+    /// assert!(is_visible(FVec3::new(0.0, 0.0, -10.0)));
+    /// assert!(!is_visible(FVec3::new(0.0, 0.0, 10.0)));
+    /// ```
+    pub fn frustum_planes(&self) -> [FVec4; 6] {
+        let [x, y, z, w] = [0, 1, 2, 3].map(|i| self.row(i));
+
+        // Points are inside the frustum when -w ≤ x ≤ w, -w ≤ y ≤ w, and
+        // -w ≤ z ≤ 0, since citro3d maps depth to [-1, 0] instead of [-1, 1].
+        [w + x, w - x, w + y, w - y, w + z, -z].map(|plane| plane / plane.truncate().magnitude())
+    }
+
+    /// Extract the upper-left 3x3 part of the matrix, which contains its
+    /// rotation and scale but not its translation.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, Matrix3, Matrix4};
+    /// let mut mtx = Matrix4::from_scale(FVec3::new(2.0, 3.0, 4.0));
+    /// mtx.translate(1.0, 2.0, 3.0);
+    /// assert_eq!(mtx.upper_3x3(), Matrix3::diagonal(2.0, 3.0, 4.0));
+    /// ```
+    pub fn upper_3x3(&self) -> Matrix3 {
+        let mut out = Matrix3::zero();
+        for row in 0..3 {
+            for col in 0..3 {
+                out[(row, col)] = self[(row, col)];
+            }
+        }
+        out
+    }
+
+    /// Construct a rotation matrix from the given quaternion.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FQuat, Matrix4};
+    /// # use approx::assert_abs_diff_eq;
+    /// let mtx = Matrix4::from_quat(FQuat::identity());
+    /// assert_abs_diff_eq!(mtx, Matrix4::identity());
+    /// ```
+    #[doc(alias = "Mtx_FromQuat")]
+    #[doc(alias = "from_quaternion")]
+    pub fn from_quat(q: FQuat) -> Self {
+        let mut out = MaybeUninit::uninit();
+        unsafe {
+            citro3d_sys::Mtx_FromQuat(out.as_mut_ptr(), q.0);
+            Self::new(out.assume_init())
+        }
+    }
+
+    /// Construct a translation matrix. This is equivalent to calling
+    /// [`translate`](Self::translate) on an [`identity`](Self::identity) matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, FVec4, Matrix4};
+    /// let mtx = Matrix4::from_translation(FVec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(mtx.column(3), FVec4::new(1.0, 2.0, 3.0, 1.0));
+    /// ```
+    pub fn from_translation(translation: FVec3) -> Self {
+        let mut out = Self::identity();
+        out.translate(translation.x(), translation.y(), translation.z());
+        out
+    }
+
+    /// Construct a scaling matrix. This is equivalent to calling
+    /// [`scale`](Self::scale) on an [`identity`](Self::identity) matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, Matrix4};
+    /// let mtx = Matrix4::from_scale(FVec3::new(1.0, 2.0, 3.0));
+    /// assert_eq!(mtx, Matrix4::diagonal(1.0, 2.0, 3.0, 1.0));
+    /// ```
+    pub fn from_scale(scale: FVec3) -> Self {
+        let mut out = Self::identity();
+        out.scale(scale.x(), scale.y(), scale.z());
+        out
+    }
+
+    /// Construct a matrix rotating `angle` radians around `axis`. This is
+    /// equivalent to calling [`rotate`](Self::rotate) on an
+    /// [`identity`](Self::identity) matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use std::f32::consts::FRAC_PI_2;
+    /// # use citro3d::math::{FVec3, Matrix4};
+    /// # use approx::assert_abs_diff_eq;
+    /// let mtx = Matrix4::from_rotation(FVec3::UNIT_Z, FRAC_PI_2);
+    /// assert_abs_diff_eq!(&mtx * FVec3::UNIT_X.extend(0.0), FVec3::UNIT_Y.extend(0.0));
+    /// ```
+    pub fn from_rotation(axis: FVec3, angle: f32) -> Self {
+        let mut out = Self::identity();
+        out.rotate(axis, angle);
+        out
+    }
+
+    /// Construct the shortest rotation which rotates the direction of `from`
+    /// to the direction of `to`. See [`FQuat::rotation_between`].
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FVec3, Matrix4};
+    /// # use approx::assert_abs_diff_eq;
+    /// let normal = FVec3::new(1.0, 1.0, 0.0).normalize();
+    /// let align = Matrix4::rotation_between(FVec3::UNIT_Y, normal);
+    /// assert_abs_diff_eq!(&align * FVec3::UNIT_Y.extend(0.0), normal.extend(0.0));
+    /// ```
+    pub fn rotation_between(from: FVec3, to: FVec3) -> Self {
+        Self::from_quat(FQuat::rotation_between(from, to))
+    }
+
+    /// Construct a matrix rotating `angle` radians around the X axis.
+    pub fn from_rotation_x(angle: f32) -> Self {
+        let mut out = Self::identity();
+        out.rotate_x(angle);
+        out
+    }
+
+    /// Construct a matrix rotating `angle` radians around the Y axis.
+    pub fn from_rotation_y(angle: f32) -> Self {
+        let mut out = Self::identity();
+        out.rotate_y(angle);
+        out
+    }
+
+    /// Construct a matrix rotating `angle` radians around the Z axis.
+    pub fn from_rotation_z(angle: f32) -> Self {
+        let mut out = Self::identity();
+        out.rotate_z(angle);
+        out
+    }
+
+    /// Construct a transformation matrix which scales, then rotates, then
+    /// translates (in that order). This is the inverse of
+    /// [`decompose`](Self::decompose).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use std::f32::consts::FRAC_PI_2;
+    /// # use citro3d::math::{FQuat, FVec3, FVec4, Matrix4};
+    /// # use approx::assert_abs_diff_eq;
+    /// let mtx = Matrix4::from_trs(
+    ///     FVec3::new(0.0, 0.0, -5.0),
+    ///     FQuat::from_axis_angle(FVec3::UNIT_Z, FRAC_PI_2),
+    ///     FVec3::splat(2.0),
+    /// );
+    ///
+    /// let point = &mtx * FVec4::new(1.0, 0.0, 0.0, 1.0);
+    /// assert_abs_diff_eq!(point, FVec4::new(0.0, 2.0, -5.0, 1.0));
+    /// ```
+    pub fn from_trs(translation: FVec3, rotation: FQuat, scale: FVec3) -> Self {
+        let mut out = Self::from_quat(rotation);
+        // Mtx_Scale always applies the scale before any existing transformation
+        out.scale(scale.x(), scale.y(), scale.z());
+        out.translate(translation.x(), translation.y(), translation.z());
+        out
+    }
+
+    /// Decompose an affine transformation matrix into its translation,
+    /// rotation, and scale components, such that
+    /// [`from_trs`](Self::from_trs) would reconstruct the original matrix.
+    ///
+    /// If the matrix is mirrored (i.e. has a negative determinant), the X
+    /// component of the scale will be negative, regardless of which axis was
+    /// originally flipped. The rotation is adjusted accordingly.
+    ///
+    /// Returns `None` if the matrix can't be represented this way, i.e. if it
+    /// has zero scale on any axis, contains shear, or is a projection matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{FQuat, FVec3, Matrix4};
+    /// # use approx::assert_abs_diff_eq;
+    /// let rotation = FQuat::from_axis_angle(FVec3::new(1.0, 1.0, 0.0), 0.5);
+    /// let mtx = Matrix4::from_trs(FVec3::new(1.0, 2.0, 3.0), rotation, FVec3::new(1.0, 2.0, 3.0));
+    ///
+    /// let (translation, _, scale) = mtx.decompose().unwrap();
+    /// assert_abs_diff_eq!(translation, FVec3::new(1.0, 2.0, 3.0));
+    /// assert_abs_diff_eq!(scale, FVec3::new(1.0, 2.0, 3.0));
+    ///
+    /// assert_eq!(Matrix4::zero().decompose(), None);
+    /// ```
+    pub fn decompose(&self) -> Option<(FVec3, FQuat, FVec3)> {
+        // Same tolerance as the `approx` impls
+        let tolerance = f32::EPSILON.sqrt();
+
+        if (self.row(3) - FVec4::UNIT_W).magnitude() > tolerance {
+            return None;
+        }
+
+        let translation = self.translation();
+
+        let mut axes = [0, 1, 2].map(|j| self.column(j).truncate());
+        let mut scale = axes.map(FVec3::magnitude);
+        if scale.iter().any(|s| *s <= f32::EPSILON) {
+            return None;
+        }
+
+        for (axis, scale) in axes.iter_mut().zip(scale) {
+            *axis /= scale;
+        }
+
+        if axes[0].cross(axes[1]).dot(axes[2]) < 0.0 {
+            // The matrix is mirrored, which we attribute to the X axis
+            axes[0] = -axes[0];
+            scale[0] = -scale[0];
+        }
+
+        let [x, y, z] = axes;
+        if x.dot(y).abs() > tolerance || x.dot(z).abs() > tolerance || y.dot(z).abs() > tolerance {
+            return None;
+        }
+
+        let rotation = Self::from_cols([
+            x.extend(0.0).as_array(),
+            y.extend(0.0).as_array(),
+            z.extend(0.0).as_array(),
+            FVec4::UNIT_W.as_array(),
+        ]);
+        let rotation = FQuat::from_matrix(&rotation);
+
+        Some((
+            translation,
+            rotation,
+            FVec3::new(scale[0], scale[1], scale[2]),
+        ))
+    }
+
     /// Construct a 3D transformation matrix for a camera, given its position,
     /// target, and upward direction.
+    ///
+    /// If `camera_up` is parallel to the direction the camera is looking, the
+    /// camera's roll is ambiguous. In that case [`FVec3::UNIT_Y`] is used as
+    /// the up direction instead, or [`FVec3::UNIT_Z`] if the camera is looking
+    /// straight up or down. The same fallback is used if `camera_up` is zero.
+    ///
+    /// If `camera_position` and `camera_target` are the same point, there is
+    /// no direction to look in, so the camera is only translated to
+    /// `camera_position`, and keeps looking down the Z axis (-Z for
+    /// right-handed coordinates, +Z for left-handed).
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{CoordinateOrientation, FVec3, Matrix4};
+    /// let position = FVec3::new(0.0, 5.0, 0.0);
+    /// // Looking straight down, with an "up" that points the same way
+    /// let mtx = Matrix4::looking_at(
+    ///     position,
+    ///     FVec3::ZERO,
+    ///     -FVec3::UNIT_Y,
+    ///     CoordinateOrientation::RightHanded,
+    /// );
+    /// assert!(mtx.to_rows().iter().flatten().all(|x| x.is_finite()));
+    /// ```
     #[doc(alias = "Mtx_LookAt")]
     pub fn looking_at(
         camera_position: FVec3,
@@ -206,6 +1152,24 @@ impl Matrix4 {
         camera_up: FVec3,
         coordinates: CoordinateOrientation,
     ) -> Self {
+        if camera_position == camera_target {
+            return Self::from_translation(-camera_position);
+        }
+
+        let forward = camera_target - camera_position;
+
+        // Mtx_LookAt normalizes `forward × up`, which results in NaNs when the
+        // two are parallel, so pick a different up axis in that case.
+        let sin_squared = forward.cross(camera_up).magnitude_squared()
+            / (forward.magnitude_squared() * camera_up.magnitude_squared());
+        let camera_up = if sin_squared > f32::EPSILON {
+            camera_up
+        } else if forward.normalize().y().abs() < 0.9 {
+            FVec3::UNIT_Y
+        } else {
+            FVec3::UNIT_Z
+        };
+
         let mut out = MaybeUninit::uninit();
         unsafe {
             citro3d_sys::Mtx_LookAt(
@@ -218,4 +1182,999 @@ impl Matrix4 {
             Self::new(out.assume_init())
         }
     }
+
+    /// Construct a perspective projection matrix, with no screen rotation
+    /// applied, for projections that aren't displayed on the 3DS screens
+    /// directly (e.g. rendering to a texture).
+    ///
+    /// This takes the same parameters as `Mtx_Persp`, and like it does not
+    /// validate them. See [`Projection::perspective`](super::Projection::perspective) for a checked builder.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use std::f32::consts::FRAC_PI_4;
+    /// # use citro3d::math::{CoordinateOrientation, Matrix4};
+    /// let shadow_projection =
+    ///     Matrix4::perspective(FRAC_PI_4, 1.0, 0.1, 50.0, CoordinateOrientation::RightHanded);
+    /// ```
+    #[doc(alias = "Mtx_Persp")]
+    pub fn perspective(
+        vertical_fov_radians: f32,
+        aspect_ratio: f32,
+        near: f32,
+        far: f32,
+        coordinates: CoordinateOrientation,
+    ) -> Self {
+        let mut out = MaybeUninit::uninit();
+        unsafe {
+            citro3d_sys::Mtx_Persp(
+                out.as_mut_ptr(),
+                vertical_fov_radians,
+                aspect_ratio,
+                near,
+                far,
+                coordinates.is_left_handed(),
+            );
+            Self::new(out.assume_init())
+        }
+    }
+
+    /// Construct a perspective projection matrix, rotated for the 3DS screens.
+    ///
+    /// This takes the same parameters as `Mtx_PerspTilt`, and like it does not
+    /// validate them. See [`Projection::perspective`](super::Projection::perspective) for a checked builder.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{AspectRatio, CoordinateOrientation, Matrix4};
+    /// // Mtx_PerspTilt(&projection, C3D_AngleFromDegrees(80.0f), C3D_AspectRatioTop, 0.01f, 1000.0f, false);
+    /// let projection = Matrix4::perspective_tilted(
+    ///     80.0_f32.to_radians(),
+    ///     AspectRatio::TopScreen.into(),
+    ///     0.01,
+    ///     1000.0,
+    ///     CoordinateOrientation::RightHanded,
+    /// );
+    /// ```
+    #[doc(alias = "Mtx_PerspTilt")]
+    pub fn perspective_tilted(
+        vertical_fov_radians: f32,
+        aspect_ratio: f32,
+        near: f32,
+        far: f32,
+        coordinates: CoordinateOrientation,
+    ) -> Self {
+        let mut out = MaybeUninit::uninit();
+        unsafe {
+            citro3d_sys::Mtx_PerspTilt(
+                out.as_mut_ptr(),
+                vertical_fov_radians,
+                aspect_ratio,
+                near,
+                far,
+                coordinates.is_left_handed(),
+            );
+            Self::new(out.assume_init())
+        }
+    }
+
+    /// Construct an orthographic projection matrix, with no screen rotation
+    /// applied, for projections that aren't displayed on the 3DS screens
+    /// directly (e.g. rendering to a texture).
+    ///
+    /// This takes the same parameters as `Mtx_Ortho`, and like it does not
+    /// validate them. See [`Projection::orthographic`](super::Projection::orthographic) for a checked builder.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{CoordinateOrientation, Matrix4};
+    /// let texture_projection = Matrix4::orthographic(
+    ///     0.0,
+    ///     256.0,
+    ///     0.0,
+    ///     256.0,
+    ///     0.0,
+    ///     1.0,
+    ///     CoordinateOrientation::RightHanded,
+    /// );
+    /// ```
+    #[doc(alias = "Mtx_Ortho")]
+    pub fn orthographic(
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+        coordinates: CoordinateOrientation,
+    ) -> Self {
+        let mut out = MaybeUninit::uninit();
+        unsafe {
+            citro3d_sys::Mtx_Ortho(
+                out.as_mut_ptr(),
+                left,
+                right,
+                bottom,
+                top,
+                near,
+                far,
+                coordinates.is_left_handed(),
+            );
+            Self::new(out.assume_init())
+        }
+    }
+}
+
+impl<const M: usize, const N: usize> From<[[f32; N]; M]> for Matrix<M, N> {
+    /// See [`Matrix::from_rows`].
+    fn from(rows: [[f32; N]; M]) -> Self {
+        Self::from_rows(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use approx::{assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq};
+
+    use super::*;
+    use crate::math::{AspectRatio, ClipPlanes, Projection, ScreenOrientation};
+
+    #[test]
+    fn rows_and_columns() {
+        let mtx = Matrix4::diagonal(1.0, 2.0, 3.0, 4.0);
+
+        assert_eq!(mtx.row(0), FVec4::new(1.0, 0.0, 0.0, 0.0));
+        assert_eq!(mtx.row(1), FVec4::new(0.0, 2.0, 0.0, 0.0));
+        assert_eq!(mtx.row(2), FVec4::new(0.0, 0.0, 3.0, 0.0));
+        assert_eq!(mtx.row(3), FVec4::new(0.0, 0.0, 0.0, 4.0));
+
+        for i in 0..4 {
+            assert_eq!(mtx.column(i), mtx.row(i));
+        }
+
+        let mut mtx = Matrix4::identity();
+        mtx[(1, 3)] = 5.0;
+        assert_eq!(mtx.to_rows()[1], [0.0, 1.0, 0.0, 5.0]);
+        assert_eq!(mtx.row(1), FVec4::new(0.0, 1.0, 0.0, 5.0));
+        assert_eq!(mtx.column(3), FVec4::new(0.0, 5.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn from_cols() {
+        let mut mtx = Matrix4::identity();
+        mtx.rotate(FVec3::new(1.0, 2.0, 3.0), 0.5);
+        mtx.translate(4.0, -5.0, 6.0);
+        let cols = mtx.transpose().to_rows();
+        assert_eq!(Matrix4::from_cols(cols), mtx);
+
+        let mtx = Matrix::<4, 3>::from_cols([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+        ]);
+        assert_eq!(
+            mtx.to_rows(),
+            [
+                [1.0, 5.0, 9.0],
+                [2.0, 6.0, 10.0],
+                [3.0, 7.0, 11.0],
+                [4.0, 8.0, 12.0],
+            ]
+        );
+
+        let rows = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+        assert_eq!(
+            Matrix3::from_cols(rows),
+            Matrix3::from_rows(rows).transpose()
+        );
+    }
+
+    #[test]
+    fn inversed() {
+        let rotation = Matrix4::from_rotation(FVec3::new(1.0, -2.0, 0.5), 1.3);
+        let inverse = rotation.inversed().unwrap();
+        assert_abs_diff_eq!(inverse, rotation.transpose(), epsilon = 1.0e-5);
+        // A rotation matrix is its own inverse transpose
+        assert_abs_diff_eq!(
+            rotation.inverse_transpose().unwrap(),
+            rotation,
+            epsilon = 1.0e-5
+        );
+
+        let rotation = Matrix3::from_rows([[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]]);
+        assert_abs_diff_eq!(rotation.inversed().unwrap(), rotation.transpose());
+
+        // Zero padding outside the 3x3 matrix doesn't make it singular
+        let mtx = Matrix3::diagonal(2.0, 4.0, 0.5);
+        assert_abs_diff_eq!(mtx.inversed().unwrap(), Matrix3::diagonal(0.5, 0.25, 2.0));
+        assert_eq!(Matrix3::diagonal(2.0, 0.0, 0.5).inversed(), None);
+
+        let singular = Matrix4::diagonal(1.0, 0.0, 1.0, 1.0);
+        assert_eq!(singular.inversed(), None);
+        assert_eq!(singular.inverse_transpose(), None);
+        // The original matrix is left untouched
+        assert_eq!(singular, Matrix4::diagonal(1.0, 0.0, 1.0, 1.0));
+
+        let mut mtx = Matrix4::from_scale(FVec3::new(2.0, 3.0, 4.0));
+        mtx.translate(1.0, 2.0, 3.0);
+        let inverse_transpose = mtx.inverse_transpose().unwrap();
+        assert_abs_diff_eq!(inverse_transpose, mtx.inversed().unwrap().transpose(),);
+        assert_abs_diff_eq!(mtx.inversed().unwrap() * mtx, Matrix4::identity());
+    }
+
+    #[test]
+    fn is_invertible() {
+        assert!(Matrix4::identity().is_invertible());
+        assert!(!Matrix4::zero().is_invertible());
+        assert!(!Matrix4::diagonal(1.0, 0.0, 1.0, 1.0).is_invertible());
+        assert!(Matrix3::identity().is_invertible());
+        assert!(!Matrix3::zero().is_invertible());
+
+        // Zero padding outside a 3x3 matrix doesn't make it singular
+        let mtx = Matrix3::from_rows([[0.0, -1.0, 0.0], [2.0, 0.0, 0.0], [0.0, 0.0, 3.0]]);
+        assert!(mtx.is_invertible());
+
+        // Rows that are multiples of each other are singular
+        let mtx = Matrix4::from_rows([
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 4.0, 6.0, 8.0],
+            [0.0, 1.0, 0.0, 1.0],
+            [1.0, 0.0, 1.0, 0.0],
+        ]);
+        assert!(!mtx.is_invertible());
+
+        for mtx in [Matrix4::from_rotation_y(0.7), Matrix4::zero()] {
+            assert_eq!(mtx.is_invertible(), mtx.inversed().is_some());
+        }
+
+        // Tiny but non-zero determinants are treated as singular, like `inverse`
+        let mtx = Matrix3::diagonal(1.0e-3, 1.0e-3, 1.0e-3);
+        assert!(mtx.determinant() > 0.0);
+        assert!(!mtx.is_invertible());
+        assert!(mtx.inversed().is_none());
+    }
+
+    #[test]
+    fn is_identity_and_zero() {
+        assert!(Matrix3::identity().is_identity(0.0));
+        assert!(Matrix4::identity().is_identity(0.0));
+        assert!(Matrix4::default().is_identity(0.0));
+        assert!(!Matrix4::zero().is_identity(0.5));
+
+        let mut mtx = Matrix4::identity();
+        mtx.translate(1.0e-4, 0.0, 0.0);
+        assert!(mtx.is_identity(1.0e-3));
+        assert!(!mtx.is_identity(1.0e-5));
+
+        let mtx = Matrix4::diagonal(1.0, 1.0, 1.0 - 1.0e-4, 1.0);
+        assert!(mtx.is_identity(1.0e-3));
+        assert!(!mtx.is_identity(1.0e-5));
+
+        assert!(Matrix4::zero().is_zero(0.0));
+        assert!(Matrix::<4, 3>::zero().is_zero(0.0));
+        assert!(!Matrix4::identity().is_zero(0.5));
+
+        let mut mtx = Matrix3::zero();
+        mtx[(2, 0)] = -1.0e-4;
+        assert!(mtx.is_zero(1.0e-3));
+        assert!(!mtx.is_zero(1.0e-5));
+
+        let mut mtx = Matrix3::identity();
+        mtx[(0, 0)] = f32::NAN;
+        assert!(!mtx.is_identity(1.0));
+        assert!(!mtx.is_zero(1.0));
+    }
+
+    #[test]
+    fn upper_3x3() {
+        let mut mtx = Matrix4::from_rotation(FVec3::new(1.0, 2.0, -1.0), 0.8);
+        let rotation = mtx;
+        mtx.translate(4.0, 5.0, 6.0);
+        assert_eq!(mtx.translation(), FVec3::new(4.0, 5.0, 6.0));
+
+        // Rotation is preserved, but translation is dropped
+        let embedded = mtx.upper_3x3().to_matrix4();
+        assert_eq!(embedded, rotation);
+        assert_eq!(embedded.translation(), FVec3::ZERO);
+
+        let mtx = Matrix3::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(
+            mtx.to_matrix4().to_rows(),
+            [
+                [1.0, 2.0, 3.0, 0.0],
+                [4.0, 5.0, 6.0, 0.0],
+                [7.0, 8.0, 9.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ]
+        );
+        assert_eq!(mtx.to_matrix4().upper_3x3(), mtx);
+    }
+
+    #[test]
+    fn orthonormalize() {
+        // A simple LCG, just to get some arbitrary but reproducible angles
+        let mut seed = 12345_u32;
+        let mut next_angle = || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 16) as f32 / u16::MAX as f32 * 0.02 - 0.01
+        };
+
+        let mut mtx = Matrix4::from_translation(FVec3::new(-1.0, 2.0, 5.0));
+        for _ in 0..5000 {
+            mtx.rotate_x_with_order(next_angle(), TransformOrder::Local);
+            mtx.rotate_y_with_order(next_angle(), TransformOrder::Local);
+            mtx.rotate_z_with_order(next_angle(), TransformOrder::Local);
+        }
+        // Exaggerate the drift a bit, in case rounding error alone isn't enough
+        mtx[(0, 1)] += 0.01;
+        mtx[(2, 2)] *= 1.01;
+
+        mtx.orthonormalize();
+
+        let axes = [0, 1, 2].map(|j| mtx.column(j).truncate());
+        for axis in axes {
+            assert_abs_diff_eq!(axis.magnitude(), 1.0);
+        }
+        assert_abs_diff_eq!(axes[0].dot(axes[1]), 0.0);
+        assert_abs_diff_eq!(axes[0].dot(axes[2]), 0.0);
+        assert_abs_diff_eq!(axes[1].dot(axes[2]), 0.0);
+        assert_abs_diff_eq!(mtx.determinant(), 1.0);
+
+        assert_eq!(mtx.translation(), FVec3::new(-1.0, 2.0, 5.0));
+        assert_eq!(mtx.row(3), FVec4::UNIT_W);
+
+        let mut mtx = Matrix3::from_rows([[1.0, 0.1, 0.0], [0.0, 1.0, 0.0], [0.1, 0.0, 2.0]]);
+        let x_axis = FVec3::new(1.0, 0.0, 0.1).normalize();
+        mtx.orthonormalize();
+        assert_abs_diff_eq!(mtx * mtx.transpose(), Matrix3::identity());
+        assert_abs_diff_eq!(FVec3::new(mtx[(0, 0)], mtx[(1, 0)], mtx[(2, 0)]), x_axis);
+    }
+
+    #[test]
+    fn transposed() {
+        let mtx = Matrix4::from_trs(
+            FVec3::new(1.0, 2.0, 3.0),
+            FQuat::from_axis_angle(FVec3::UNIT_Y, 0.3),
+            FVec3::new(2.0, 1.0, 0.5),
+        );
+        let transposed = mtx.transposed();
+        assert_ne!(transposed, mtx);
+        assert_eq!(transposed, mtx.transpose());
+        assert_eq!(transposed.transposed(), mtx);
+
+        let mtx = Matrix3::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(
+            mtx.transposed().to_rows(),
+            [[1.0, 4.0, 7.0], [2.0, 5.0, 8.0], [3.0, 6.0, 9.0]]
+        );
+        assert_eq!(mtx.transposed().transposed(), mtx);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            Matrix4::identity().to_string(),
+            "(1, 0, 0, 0)\n\
+             (0, 1, 0, 0)\n\
+             (0, 0, 1, 0)\n\
+             (0, 0, 0, 1)"
+        );
+
+        let mtx = Matrix4::from_translation(FVec3::new(-2.5, 10.0, 0.3));
+        assert_eq!(
+            mtx.to_string(),
+            "(1, 0, 0, -2.5)\n\
+             (0, 1, 0,   10)\n\
+             (0, 0, 1,  0.3)\n\
+             (0, 0, 0,    1)"
+        );
+        assert_eq!(
+            format!("{mtx:.1}"),
+            "(1.0, 0.0, 0.0, -2.5)\n\
+             (0.0, 1.0, 0.0, 10.0)\n\
+             (0.0, 0.0, 1.0,  0.3)\n\
+             (0.0, 0.0, 0.0,  1.0)"
+        );
+
+        let mtx = Matrix::<4, 3>::from_rows([
+            [1.0, -2.0, 3.0],
+            [4.0, 5.0, -6.0],
+            [7.0, 8.0, 9.0],
+            [10.0, 11.0, 12.0],
+        ]);
+        assert_eq!(
+            format!("{mtx:3}"),
+            "(  1,  -2,   3)\n\
+             (  4,   5,  -6)\n\
+             (  7,   8,   9)\n\
+             ( 10,  11,  12)"
+        );
+
+        let mtx = Matrix3::diagonal(0.5, -1.0 / 3.0, 100.0);
+        assert_eq!(
+            format!("{mtx:7.3}"),
+            "(  0.500,   0.000,   0.000)\n\
+             (  0.000,  -0.333,   0.000)\n\
+             (  0.000,   0.000, 100.000)"
+        );
+    }
+
+    #[test]
+    fn trace() {
+        assert_eq!(Matrix3::identity().trace(), 3.0);
+        assert_eq!(Matrix4::identity().trace(), 4.0);
+        assert_eq!(Matrix4::zero().trace(), 0.0);
+        assert_eq!(Matrix4::diagonal(1.0, 2.0, 3.0, 4.0).trace(), 10.0);
+
+        // Translation only affects the last column, not the diagonal
+        let mut mtx = Matrix4::from_scale(FVec3::new(2.0, 3.0, 4.0));
+        mtx.translate(5.0, 6.0, 7.0);
+        assert_eq!(mtx.trace(), 10.0);
+
+        // Rotation by θ has trace 1 + 2cos(θ) in the upper 3x3
+        let mtx = Matrix4::from_rotation(FVec3::new(1.0, 2.0, 3.0), 0.5);
+        assert_abs_diff_eq!(mtx.trace(), 2.0 + 2.0 * 0.5f32.cos(), epsilon = 1.0e-5);
+    }
+
+    #[test]
+    fn zeroable() {
+        assert_eq!(<Matrix4 as bytemuck::Zeroable>::zeroed(), Matrix4::zero());
+        assert_eq!(<Matrix3 as bytemuck::Zeroable>::zeroed(), Matrix3::zero());
+    }
+
+    #[test]
+    fn bytemuck() {
+        let mtx = Matrix4::from_rows([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ]);
+        let floats: [f32; 16] = bytemuck::cast(mtx);
+        assert_eq!(&floats[..4], [4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(&floats[12..], [16.0, 15.0, 14.0, 13.0]);
+        assert_eq!(bytemuck::bytes_of(&mtx).len(), 64);
+        assert_eq!(bytemuck::bytes_of(&Matrix3::identity()).len(), 64);
+    }
+
+    #[test]
+    fn raw_round_trip() {
+        let mtx = Matrix3::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let mut raw = mtx.into_raw();
+        assert_eq!(unsafe { (*mtx.as_raw()).r[1].c }, unsafe { raw.r[1].c });
+
+        unsafe { citro3d_sys::Mtx_Transpose(&mut raw) };
+        let transposed = unsafe { Matrix3::from_raw(raw) };
+        assert_eq!(transposed, mtx.transposed());
+
+        let v = FVec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(FVec4::from_raw(v.into_raw()), v);
+    }
+
+    #[test]
+    fn is_finite() {
+        assert!(Matrix4::identity().is_finite());
+        assert!(Matrix3::zero().is_finite());
+
+        let mut mtx = Matrix3::identity();
+        mtx[(1, 1)] = f32::MIN_POSITIVE / 2.0;
+        assert!(mtx.is_finite());
+        mtx[(2, 0)] = f32::INFINITY;
+        assert!(!mtx.is_finite());
+
+        // Elements outside of the 3x3 matrix don't matter
+        let mut mtx = Matrix4::identity();
+        mtx[(3, 3)] = f32::NAN;
+        assert!(!mtx.is_finite());
+        let mtx: Matrix3 = Matrix::new(mtx.into_raw());
+        assert!(mtx.is_finite());
+    }
+
+    #[test]
+    fn get_set() {
+        let mut mtx = Matrix::<4, 3>::zero();
+        for row in 0..4 {
+            for col in 0..3 {
+                mtx.set(row, col, (row * 3 + col) as f32);
+            }
+        }
+
+        assert_eq!(mtx.get(0, 0), 0.0);
+        assert_eq!(mtx.get(1, 2), 5.0);
+        assert_eq!(mtx.get(3, 1), 10.0);
+        assert_eq!(mtx.get(2, 0), mtx[(2, 0)]);
+        assert_eq!(
+            mtx.to_rows(),
+            [
+                [0.0, 1.0, 2.0],
+                [3.0, 4.0, 5.0],
+                [6.0, 7.0, 8.0],
+                [9.0, 10.0, 11.0],
+            ]
+        );
+    }
+
+    #[test]
+    fn to_rows() {
+        let mut mtx = Matrix3::identity();
+        mtx[(0, 1)] = 2.0;
+        mtx[(2, 0)] = 3.0;
+        assert_eq!(
+            mtx.to_rows(),
+            [[1.0, 2.0, 0.0], [0.0, 1.0, 0.0], [3.0, 0.0, 1.0]]
+        );
+        assert_eq!(
+            format!("{mtx:?}"),
+            format!("Matrix<3, 3>({:?})", mtx.to_rows())
+        );
+    }
+
+    #[test]
+    fn from_rows() {
+        let mut mtx = Matrix4::identity();
+        mtx.rotate(FVec3::new(1.0, 2.0, 3.0), 0.5);
+        mtx.translate(4.0, -5.0, 6.0);
+        assert_eq!(Matrix4::from_rows(mtx.to_rows()), mtx);
+
+        let rows = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+        let mtx = Matrix3::from(rows);
+        assert_eq!(mtx.to_rows(), rows);
+        assert_eq!(mtx[(1, 0)], 4.0);
+
+        // Padding outside the 3x3 matrix is zeroed
+        let raw = unsafe { (*mtx.as_raw()).r };
+        assert_eq!(unsafe { raw[3].c }, [0.0; 4]);
+        for row in &raw[..3] {
+            assert_eq!(unsafe { row.c[0] }, 0.0);
+        }
+    }
+
+    #[test]
+    fn transform_constructors() {
+        let v = FVec3::new(1.5, -2.0, 0.25);
+
+        let mut expected = Matrix4::identity();
+        expected.translate(1.5, -2.0, 0.25);
+        assert_abs_diff_eq!(Matrix4::from_translation(v), expected);
+
+        let mut expected = Matrix4::identity();
+        expected.scale(1.5, -2.0, 0.25);
+        assert_abs_diff_eq!(Matrix4::from_scale(v), expected);
+
+        for angle in [0.0, 0.3, -1.2, 3.0] {
+            let mut expected = Matrix4::identity();
+            expected.rotate(v, angle);
+            assert_abs_diff_eq!(Matrix4::from_rotation(v, angle), expected);
+
+            let mut expected = Matrix4::identity();
+            expected.rotate_x(angle);
+            assert_abs_diff_eq!(Matrix4::from_rotation_x(angle), expected);
+            assert_abs_diff_eq!(Matrix4::from_rotation(FVec3::UNIT_X, angle), expected);
+
+            let mut expected = Matrix4::identity();
+            expected.rotate_y(angle);
+            assert_abs_diff_eq!(Matrix4::from_rotation_y(angle), expected);
+            assert_abs_diff_eq!(Matrix4::from_rotation(FVec3::UNIT_Y, angle), expected);
+
+            let mut expected = Matrix4::identity();
+            expected.rotate_z(angle);
+            assert_abs_diff_eq!(Matrix4::from_rotation_z(angle), expected);
+            assert_abs_diff_eq!(Matrix4::from_rotation(FVec3::UNIT_Z, angle), expected);
+        }
+    }
+
+    #[test]
+    fn transform_point_and_vector() {
+        let mut mtx = Matrix4::from_rotation_z(FRAC_PI_2);
+        mtx.translate(1.0, 2.0, 3.0);
+
+        // Points are rotated, then translated
+        assert_abs_diff_eq!(
+            mtx.transform_point(FVec3::new(1.0, 0.0, 0.0)),
+            FVec3::new(1.0, 3.0, 3.0),
+            epsilon = 1.0e-5
+        );
+        // Vectors are only rotated
+        assert_abs_diff_eq!(
+            mtx.transform_vector(FVec3::new(1.0, 0.0, 0.0)),
+            FVec3::new(0.0, 1.0, 0.0),
+            epsilon = 1.0e-5
+        );
+
+        // A non-unit w is divided out for points
+        let mut mtx = Matrix4::identity();
+        mtx[(3, 3)] = 2.0;
+        assert_abs_diff_eq!(
+            mtx.transform_point(FVec3::new(2.0, 4.0, 6.0)),
+            FVec3::new(1.0, 2.0, 3.0)
+        );
+        assert_abs_diff_eq!(
+            mtx.transform_vector(FVec3::new(2.0, 4.0, 6.0)),
+            FVec3::new(2.0, 4.0, 6.0)
+        );
+    }
+
+    #[test]
+    fn projection_constructors() {
+        let clip_planes = ClipPlanes {
+            near: 0.1,
+            far: 100.0,
+        };
+
+        for coordinates in [
+            CoordinateOrientation::LeftHanded,
+            CoordinateOrientation::RightHanded,
+        ] {
+            let mut mtx = Matrix4::perspective(
+                FRAC_PI_2,
+                AspectRatio::TopScreen.into(),
+                clip_planes.near,
+                clip_planes.far,
+                coordinates,
+            );
+            let tilted = Projection::perspective(FRAC_PI_2, AspectRatio::TopScreen, clip_planes)
+                .coordinates(coordinates)
+                .to_matrix();
+            assert_abs_diff_ne!(mtx, tilted);
+            assert_abs_diff_eq!(
+                Matrix4::perspective_tilted(
+                    FRAC_PI_2,
+                    AspectRatio::TopScreen.into(),
+                    clip_planes.near,
+                    clip_planes.far,
+                    coordinates,
+                ),
+                tilted
+            );
+            // The tilted matrix is rotated 90° clockwise for the 3DS screens
+            mtx.rotate_z(-FRAC_PI_2);
+            assert_abs_diff_eq!(mtx, tilted);
+
+            let mut mtx = Matrix4::orthographic(
+                0.0,
+                400.0,
+                0.0,
+                240.0,
+                clip_planes.near,
+                clip_planes.far,
+                coordinates,
+            );
+            let tilted = Projection::orthographic(0.0..400.0, 0.0..240.0, clip_planes)
+                .coordinates(coordinates)
+                .to_matrix();
+            assert_abs_diff_ne!(mtx, tilted);
+            mtx.rotate_z(-FRAC_PI_2);
+            assert_abs_diff_eq!(mtx, tilted);
+        }
+    }
+
+    #[test]
+    fn frustum_planes() {
+        let clip_planes = ClipPlanes {
+            near: 0.1,
+            far: 100.0,
+        };
+        let projection = Projection::perspective(FRAC_PI_2, AspectRatio::Other(1.0), clip_planes);
+
+        for screen in [ScreenOrientation::Rotated, ScreenOrientation::None] {
+            let projection = projection.clone().screen(screen).to_matrix();
+            let planes = projection.frustum_planes();
+
+            for plane in planes {
+                assert_abs_diff_eq!(plane.truncate().magnitude(), 1.0, epsilon = 1.0e-5);
+            }
+
+            let distances = |p: FVec3| planes.map(|plane| plane.dot(p.to_homogeneous()));
+            let is_inside = |p: FVec3| distances(p).iter().all(|&d| d >= 0.0);
+
+            assert!(is_inside(FVec3::new(0.0, 0.0, -1.0)));
+            assert!(is_inside(FVec3::new(4.0, -4.0, -5.0)));
+            assert!(is_inside(FVec3::new(-90.0, 90.0, -99.0)));
+
+            assert!(!is_inside(FVec3::new(0.0, 0.0, 1.0)));
+            assert!(!is_inside(FVec3::new(0.0, 0.0, -0.05)));
+            assert!(!is_inside(FVec3::new(0.0, 0.0, -101.0)));
+            assert!(!is_inside(FVec3::new(6.0, 0.0, -5.0)));
+            assert!(!is_inside(FVec3::new(-6.0, 0.0, -5.0)));
+            assert!(!is_inside(FVec3::new(0.0, 6.0, -5.0)));
+            assert!(!is_inside(FVec3::new(0.0, -6.0, -5.0)));
+
+            // Distances to the near and far planes are in world units
+            let [.., near, far] = distances(FVec3::new(0.0, 0.0, -1.0));
+            assert_abs_diff_eq!(near, 0.9, epsilon = 1.0e-4);
+            assert_abs_diff_eq!(far, 99.0, epsilon = 1.0e-2);
+
+            // Planes move with the view when extracted from projection * view
+            let view = Matrix4::from_translation(FVec3::new(0.0, 0.0, -10.0));
+            let planes = (projection * view).frustum_planes();
+            let is_inside = |p: FVec3| {
+                planes
+                    .iter()
+                    .all(|plane| plane.dot(p.to_homogeneous()) >= 0.0)
+            };
+            assert!(is_inside(FVec3::new(0.0, 0.0, 5.0)));
+            assert!(!is_inside(FVec3::new(0.0, 0.0, -95.0)));
+        }
+
+        let orthographic = Matrix4::orthographic(
+            -1.0,
+            1.0,
+            -2.0,
+            2.0,
+            clip_planes.near,
+            clip_planes.far,
+            CoordinateOrientation::RightHanded,
+        );
+        let planes = orthographic.frustum_planes();
+        let is_inside = |p: FVec3| {
+            planes
+                .iter()
+                .all(|plane| plane.dot(p.to_homogeneous()) >= 0.0)
+        };
+        assert!(is_inside(FVec3::new(0.5, 1.5, -50.0)));
+        assert!(!is_inside(FVec3::new(1.5, 0.0, -50.0)));
+        assert!(!is_inside(FVec3::new(0.0, -2.5, -50.0)));
+        assert!(!is_inside(FVec3::new(0.0, 0.0, 0.0)));
+        assert!(!is_inside(FVec3::new(0.0, 0.0, -150.0)));
+    }
+
+    #[test]
+    fn from_trs() {
+        let translation = FVec3::new(1.0, -2.0, 3.0);
+        let rotation = FQuat::from_axis_angle(FVec3::new(0.0, 1.0, 1.0).normalize(), 0.9);
+        let scale = FVec3::new(2.0, 0.5, 4.0);
+
+        // Scale first, then rotate, then translate
+        let expected = (Matrix4::from_translation(translation) * rotation.to_matrix())
+            * Matrix4::from_scale(scale);
+        assert_abs_diff_eq!(
+            Matrix4::from_trs(translation, rotation, scale),
+            expected,
+            epsilon = 1.0e-5
+        );
+
+        assert_eq!(
+            Matrix4::from_trs(FVec3::ZERO, FQuat::identity(), FVec3::ONE),
+            Matrix4::identity()
+        );
+    }
+
+    #[test]
+    fn trs_round_trip() {
+        let translations = [FVec3::ZERO, FVec3::new(1.0, -2.0, 30.0)];
+        let rotations = [
+            FQuat::identity(),
+            FQuat::from_axis_angle(FVec3::UNIT_X, 0.5),
+            FQuat::from_axis_angle(FVec3::UNIT_Y, -2.0),
+            FQuat::from_axis_angle(FVec3::new(1.0, 2.0, 3.0).normalize(), 3.0),
+            FQuat::from_axis_angle(FVec3::new(-1.0, 0.5, 0.2).normalize(), 1.2),
+        ];
+        let scales = [
+            FVec3::ONE,
+            FVec3::new(2.0, 0.5, 3.0),
+            FVec3::new(-1.5, 1.0, 0.25),
+        ];
+
+        for translation in translations {
+            for rotation in rotations {
+                for scale in scales {
+                    let mtx = Matrix4::from_trs(translation, rotation, scale);
+                    let (t, r, s) = mtx.decompose().unwrap();
+
+                    assert_abs_diff_eq!(t, translation, epsilon = 1.0e-5);
+                    assert_abs_diff_eq!(s, scale, epsilon = 1.0e-5);
+                    // q and -q represent the same rotation
+                    assert_abs_diff_eq!(r.dot(rotation).abs(), 1.0, epsilon = 1.0e-5);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn decompose_baked() {
+        let mut mtx = Matrix4::identity();
+        mtx.scale(0.5, 2.0, 1.5);
+        mtx.rotate_x(0.4);
+        mtx.rotate_y(-1.1);
+        mtx.translate(10.0, -3.0, 0.5);
+
+        let (t, r, s) = mtx.decompose().unwrap();
+        assert_abs_diff_eq!(t, FVec3::new(10.0, -3.0, 0.5), epsilon = 1.0e-5);
+        assert_abs_diff_eq!(s, FVec3::new(0.5, 2.0, 1.5), epsilon = 1.0e-5);
+
+        let mut expected = Matrix4::from_rotation_x(0.4);
+        expected.rotate_y(-1.1);
+        assert_abs_diff_eq!(r.to_matrix(), expected, epsilon = 1.0e-5);
+
+        // Components can be re-interpolated and recomposed
+        let halfway = Matrix4::from_trs(
+            t.lerp(FVec3::ZERO, 0.5),
+            r.slerp(FQuat::identity(), 0.5),
+            s.lerp(FVec3::ONE, 0.5),
+        );
+        let (t, _, s) = halfway.decompose().unwrap();
+        assert_abs_diff_eq!(t, FVec3::new(5.0, -1.5, 0.25), epsilon = 1.0e-5);
+        assert_abs_diff_eq!(s, FVec3::new(0.75, 1.5, 1.25), epsilon = 1.0e-5);
+    }
+
+    #[test]
+    fn decompose_mirrored() {
+        let rotation = FQuat::from_axis_angle(FVec3::new(1.0, 2.0, 3.0).normalize(), 0.8);
+        let mtx = Matrix4::from_trs(FVec3::ONE, rotation, FVec3::new(2.0, -3.0, 4.0));
+
+        let (t, r, s) = mtx.decompose().unwrap();
+        // The sign is always attributed to the X axis
+        assert_abs_diff_eq!(s, FVec3::new(-2.0, 3.0, 4.0), epsilon = 1.0e-5);
+        assert_abs_diff_eq!(Matrix4::from_trs(t, r, s), mtx, epsilon = 1.0e-5);
+    }
+
+    #[test]
+    fn decompose_degenerate() {
+        let mtx = Matrix4::from_scale(FVec3::new(1.0, 0.0, 1.0));
+        assert_eq!(mtx.decompose(), None);
+
+        let mut sheared = Matrix4::identity();
+        sheared[(0, 1)] = 0.5;
+        assert_eq!(sheared.decompose(), None);
+
+        let mut projective = Matrix4::identity();
+        projective[(3, 2)] = -1.0;
+        assert_eq!(projective.decompose(), None);
+    }
+
+    #[test]
+    fn transform_order() {
+        let mut world = Matrix4::identity();
+        world.translate_with_order(1.0, 2.0, 3.0, TransformOrder::World);
+        world.rotate_with_order(FVec3::new(1.0, 1.0, 0.0), 0.5, TransformOrder::World);
+
+        let mut local = Matrix4::identity();
+        local.translate_with_order(1.0, 2.0, 3.0, TransformOrder::Local);
+        local.rotate_with_order(FVec3::new(1.0, 1.0, 0.0), 0.5, TransformOrder::Local);
+
+        assert_abs_diff_ne!(world, local);
+
+        let translation = Matrix4::from_translation(FVec3::new(1.0, 2.0, 3.0));
+        let rotation = Matrix4::from_rotation(FVec3::new(1.0, 1.0, 0.0), 0.5);
+        assert_abs_diff_eq!(world, rotation * translation);
+        assert_abs_diff_eq!(local, translation * rotation);
+
+        // The default methods use world order
+        let mut mtx = Matrix4::identity();
+        mtx.translate(1.0, 2.0, 3.0);
+        mtx.rotate(FVec3::new(1.0, 1.0, 0.0), 0.5);
+        assert_eq!(mtx, world);
+
+        let local_x = |angle| {
+            let mut mtx = translation;
+            mtx.rotate_x_with_order(angle, TransformOrder::Local);
+            mtx
+        };
+        let local_y = |angle| {
+            let mut mtx = translation;
+            mtx.rotate_y_with_order(angle, TransformOrder::Local);
+            mtx
+        };
+        let local_z = |angle| {
+            let mut mtx = translation;
+            mtx.rotate_z_with_order(angle, TransformOrder::Local);
+            mtx
+        };
+        assert_abs_diff_eq!(local_x(0.3), translation * Matrix4::from_rotation_x(0.3));
+        assert_abs_diff_eq!(local_y(-0.7), translation * Matrix4::from_rotation_y(-0.7));
+        assert_abs_diff_eq!(local_z(1.1), translation * Matrix4::from_rotation_z(1.1));
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(Matrix3::default(), Matrix3::identity());
+        assert_eq!(Matrix4::default(), Matrix4::identity());
+        assert_eq!(FVec3::default(), FVec3::ZERO);
+        assert_eq!(FVec4::default(), FVec4::ZERO);
+    }
+
+    #[test]
+    fn looking_at_parallel_up() {
+        let position = FVec3::new(1.0, 2.0, 3.0);
+        let target = FVec3::new(-2.0, 0.5, 1.0);
+        let up = (target - position).normalize();
+
+        for coordinates in [
+            CoordinateOrientation::LeftHanded,
+            CoordinateOrientation::RightHanded,
+        ] {
+            for up in [up, -up, up * 3.0] {
+                let mtx = Matrix4::looking_at(position, target, up, coordinates);
+                let expected = Matrix4::looking_at(position, target, FVec3::UNIT_Y, coordinates);
+                assert_eq!(mtx, expected);
+            }
+        }
+
+        // Looking straight down can't fall back to +Y as up
+        let mtx = Matrix4::looking_at(
+            FVec3::UNIT_Y,
+            FVec3::ZERO,
+            FVec3::UNIT_Y,
+            CoordinateOrientation::RightHanded,
+        );
+        assert!(mtx.to_rows().iter().flatten().all(|x| x.is_finite()));
+
+        // A zero up vector falls back the same way
+        for coordinates in [
+            CoordinateOrientation::LeftHanded,
+            CoordinateOrientation::RightHanded,
+        ] {
+            let mtx = Matrix4::looking_at(position, target, FVec3::ZERO, coordinates);
+            let expected = Matrix4::looking_at(position, target, FVec3::UNIT_Y, coordinates);
+            assert_eq!(mtx, expected);
+        }
+
+        // Looking at the camera's own position only translates it, facing the
+        // default direction for the coordinate orientation
+        for (coordinates, forward) in [
+            (CoordinateOrientation::LeftHanded, FVec3::UNIT_Z),
+            (CoordinateOrientation::RightHanded, -FVec3::UNIT_Z),
+        ] {
+            for up in [FVec3::UNIT_Y, FVec3::ZERO] {
+                let mtx = Matrix4::looking_at(position, position, up, coordinates);
+                let expected =
+                    Matrix4::looking_at(position, position + forward, FVec3::UNIT_Y, coordinates);
+                assert_abs_diff_eq!(mtx, expected, epsilon = 1.0e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn determinant() {
+        assert_eq!(Matrix3::identity().determinant(), 1.0);
+        assert_eq!(Matrix4::identity().determinant(), 1.0);
+
+        assert_abs_diff_eq!(Matrix3::diagonal(2.0, 3.0, 4.0).determinant(), 24.0);
+        assert_abs_diff_eq!(Matrix3::diagonal(2.0, 0.0, 4.0).determinant(), 0.0);
+        assert_abs_diff_eq!(Matrix4::diagonal(2.0, 3.0, 0.0, 1.0).determinant(), 0.0);
+
+        let mut mtx = Matrix4::identity();
+        mtx.scale(2.0, -3.0, 0.5);
+        assert_abs_diff_eq!(mtx.determinant(), -3.0);
+
+        // Rotation and translation don't affect the determinant
+        mtx.rotate(FVec3::new(1.0, 1.0, 0.0), 0.7);
+        mtx.translate(5.0, 6.0, 7.0);
+        assert_abs_diff_eq!(mtx.determinant(), -3.0, epsilon = 1.0e-5);
+
+        // Small scales still give the product of the scales, even if the
+        // matrix is too close to singular to invert
+        let mtx = Matrix4::from_scale(FVec3::splat(0.004));
+        assert_relative_eq!(mtx.determinant(), 6.4e-8);
+        assert_relative_eq!(
+            Matrix4::diagonal(0.004, 0.004, -0.004, 1.0).determinant(),
+            -6.4e-8
+        );
+
+        let rows = [
+            [1.0, 2.0, 0.0, 4.0],
+            [0.0, 1.0, 3.0, 0.0],
+            [2.0, 0.0, 1.0, 1.0],
+            [0.0, 3.0, 0.0, 2.0],
+        ];
+        let mut copy = Matrix4::from_rows(rows);
+        let expected = unsafe { citro3d_sys::Mtx_Inverse(copy.as_mut()) };
+        assert_abs_diff_eq!(
+            Matrix4::from_rows(rows).determinant(),
+            expected,
+            epsilon = 1.0e-4
+        );
+
+        let rows = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]];
+        assert_abs_diff_eq!(
+            Matrix3::from_rows(rows).determinant(),
+            -3.0,
+            epsilon = 1.0e-5
+        );
+    }
 }
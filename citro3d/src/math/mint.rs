@@ -0,0 +1,163 @@
+//! Conversions to and from [`mint`] types, for interoperability with other
+//! math libraries.
+//!
+//! All conversions use the logical order of components (XYZW, or IJK plus a
+//! scalar for quaternions) rather than the internal WZYX layout.
+
+use super::{FQuat, FVec3, FVec4, Matrix4};
+
+#[doc(cfg(feature = "mint"))]
+impl From<mint::Vector3<f32>> for FVec3 {
+    fn from(v: mint::Vector3<f32>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+#[doc(cfg(feature = "mint"))]
+impl From<FVec3> for mint::Vector3<f32> {
+    fn from(v: FVec3) -> Self {
+        Self {
+            x: v.x(),
+            y: v.y(),
+            z: v.z(),
+        }
+    }
+}
+
+#[doc(cfg(feature = "mint"))]
+impl From<mint::Vector4<f32>> for FVec4 {
+    fn from(v: mint::Vector4<f32>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+#[doc(cfg(feature = "mint"))]
+impl From<FVec4> for mint::Vector4<f32> {
+    fn from(v: FVec4) -> Self {
+        Self {
+            x: v.x(),
+            y: v.y(),
+            z: v.z(),
+            w: v.w(),
+        }
+    }
+}
+
+#[doc(cfg(feature = "mint"))]
+impl From<mint::Quaternion<f32>> for FQuat {
+    fn from(q: mint::Quaternion<f32>) -> Self {
+        Self::new(q.v.x, q.v.y, q.v.z, q.s)
+    }
+}
+
+#[doc(cfg(feature = "mint"))]
+impl From<FQuat> for mint::Quaternion<f32> {
+    fn from(q: FQuat) -> Self {
+        let ijkr = unsafe { q.0.__bindgen_anon_2 };
+        Self {
+            v: mint::Vector3 {
+                x: ijkr.i,
+                y: ijkr.j,
+                z: ijkr.k,
+            },
+            s: ijkr.r,
+        }
+    }
+}
+
+#[doc(cfg(feature = "mint"))]
+impl From<mint::RowMatrix4<f32>> for Matrix4 {
+    fn from(m: mint::RowMatrix4<f32>) -> Self {
+        Self::from_rows(m.into())
+    }
+}
+
+#[doc(cfg(feature = "mint"))]
+impl From<Matrix4> for mint::RowMatrix4<f32> {
+    fn from(m: Matrix4) -> Self {
+        m.to_rows().into()
+    }
+}
+
+#[doc(cfg(feature = "mint"))]
+impl From<mint::ColumnMatrix4<f32>> for Matrix4 {
+    fn from(m: mint::ColumnMatrix4<f32>) -> Self {
+        // Each column becomes a row, so transpose to get them back in place
+        Self::from_rows(m.into()).transpose()
+    }
+}
+
+#[doc(cfg(feature = "mint"))]
+impl From<Matrix4> for mint::ColumnMatrix4<f32> {
+    fn from(m: Matrix4) -> Self {
+        m.transpose().to_rows().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vectors() {
+        let v = FVec3::new(1.0, 2.0, 3.0);
+        let m: mint::Vector3<f32> = v.into();
+        assert_eq!(<[f32; 3]>::from(m), [1.0, 2.0, 3.0]);
+        assert_eq!(FVec3::from(m), v);
+
+        let v = FVec4::new(1.0, 2.0, 3.0, 4.0);
+        let m: mint::Vector4<f32> = v.into();
+        assert_eq!(<[f32; 4]>::from(m), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(FVec4::from(m), v);
+    }
+
+    #[test]
+    fn quaternion() {
+        let q = FQuat::new(1.0, 2.0, 3.0, 4.0);
+        let m: mint::Quaternion<f32> = q.into();
+        assert_eq!(<[f32; 3]>::from(m.v), [1.0, 2.0, 3.0]);
+        assert_eq!(m.s, 4.0);
+        assert_eq!(FQuat::from(m), q);
+    }
+
+    #[test]
+    fn matrix() {
+        let mut mtx = Matrix4::identity();
+        mtx.translate(1.0, 2.0, 3.0);
+
+        let rows: mint::RowMatrix4<f32> = mtx.into();
+        assert_eq!(<[f32; 4]>::from(rows.x), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(Matrix4::from(rows), mtx);
+
+        let columns: mint::ColumnMatrix4<f32> = mtx.into();
+        assert_eq!(<[f32; 4]>::from(columns.w), [1.0, 2.0, 3.0, 1.0]);
+        assert_eq!(Matrix4::from(columns), mtx);
+    }
+
+    #[test]
+    fn column_matrix_round_trip() {
+        // Each inner array is a column, so this is a translation by (5, 6, 7)
+        // combined with a scale of (1, 2, 3)
+        let columns = mint::ColumnMatrix4::from([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 2.0, 0.0, 0.0],
+            [0.0, 0.0, 3.0, 0.0],
+            [5.0, 6.0, 7.0, 1.0],
+        ]);
+
+        let mtx = Matrix4::from(columns);
+        assert_eq!(mtx.row(0), FVec4::new(1.0, 0.0, 0.0, 5.0));
+        assert_eq!(mtx.column(3), FVec4::new(5.0, 6.0, 7.0, 1.0));
+        assert_eq!(mtx[(1, 1)], 2.0);
+        assert_eq!(
+            mtx * FVec4::new(1.0, 1.0, 1.0, 1.0),
+            FVec4::new(6.0, 8.0, 10.0, 1.0)
+        );
+
+        let round_trip: mint::ColumnMatrix4<f32> = mtx.into();
+        assert_eq!(
+            <[[f32; 4]; 4]>::from(round_trip),
+            <[[f32; 4]; 4]>::from(columns)
+        );
+    }
+}
@@ -1,11 +1,55 @@
 use std::borrow::Borrow;
+use std::hash::{Hash, Hasher};
 use std::mem::MaybeUninit;
-use std::ops::{Add, Deref, Div, Mul, Neg, Sub};
+use std::ops::{
+    Add, AddAssign, Deref, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 #[cfg(feature = "approx")]
-use approx::AbsDiffEq;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
-use super::{FVec, FVec3, FVec4, Matrix, Matrix3, Matrix4};
+use citro3d_sys::{C3D_FVec, C3D_Mtx};
+
+use super::{FQuat, FVec, FVec3, FVec4, Matrix, Matrix3, Matrix4};
+
+// region: Native vector operations
+//
+// These are simple enough that going through the C wrappers generated for
+// citro3d's `static inline` functions costs more than the operations
+// themselves, so they're reimplemented here. They must produce the same
+// results as their C counterparts, bit for bit.
+
+/// Apply `f` to each component of `v`. Components are independent, so it
+/// doesn't matter that they're stored in WZYX order.
+fn map_components(v: C3D_FVec, f: impl Fn(f32) -> f32) -> C3D_FVec {
+    C3D_FVec {
+        c: unsafe { v.c }.map(f),
+    }
+}
+
+/// Apply `f` to each pair of corresponding components of `lhs` and `rhs`.
+fn zip_components(lhs: C3D_FVec, rhs: C3D_FVec, f: impl Fn(f32, f32) -> f32) -> C3D_FVec {
+    let (lhs, rhs) = unsafe { (lhs.c, rhs.c) };
+    C3D_FVec {
+        c: std::array::from_fn(|i| f(lhs[i], rhs[i])),
+    }
+}
+
+/// Zero the unused W component of an [`FVec3`] result, as `FVec3_New` does.
+fn without_w(mut v: C3D_FVec) -> C3D_FVec {
+    v.__bindgen_anon_1.w = 0.0;
+    v
+}
+
+/// Apply `f` to each pair of corresponding elements of `lhs` and `rhs`.
+fn zip_elements(lhs: &C3D_Mtx, rhs: &C3D_Mtx, f: impl Fn(f32, f32) -> f32) -> C3D_Mtx {
+    let (lhs, rhs) = unsafe { (lhs.m, rhs.m) };
+    C3D_Mtx {
+        m: std::array::from_fn(|i| f(lhs[i], rhs[i])),
+    }
+}
+
+// endregion
 
 // region: FVec4 math operators
 
@@ -14,7 +58,7 @@ impl Add for FVec4 {
 
     #[doc(alias = "FVec4_Add")]
     fn add(self, rhs: Self) -> Self::Output {
-        Self(unsafe { citro3d_sys::FVec4_Add(self.0, rhs.0) })
+        Self(zip_components(self.0, rhs.0, |l, r| l + r))
     }
 }
 
@@ -23,7 +67,7 @@ impl Sub for FVec4 {
 
     #[doc(alias = "FVec4_Subtract")]
     fn sub(self, rhs: Self) -> Self::Output {
-        Self(unsafe { citro3d_sys::FVec4_Subtract(self.0, rhs.0) })
+        Self(zip_components(self.0, rhs.0, |l, r| l - r))
     }
 }
 
@@ -32,7 +76,7 @@ impl Neg for FVec4 {
 
     #[doc(alias = "FVec4_Negate")]
     fn neg(self) -> Self::Output {
-        Self(unsafe { citro3d_sys::FVec4_Negate(self.0) })
+        Self(map_components(self.0, |c| -c))
     }
 }
 
@@ -41,7 +85,7 @@ impl Mul<f32> for FVec4 {
 
     #[doc(alias = "FVec4_Scale")]
     fn mul(self, rhs: f32) -> Self::Output {
-        Self(unsafe { citro3d_sys::FVec4_Scale(self.0, rhs) })
+        Self(map_components(self.0, |c| c * rhs))
     }
 }
 
@@ -54,7 +98,7 @@ impl Add for FVec3 {
 
     #[doc(alias = "FVec3_Add")]
     fn add(self, rhs: Self) -> Self::Output {
-        Self(unsafe { citro3d_sys::FVec3_Add(self.0, rhs.0) })
+        Self(without_w(zip_components(self.0, rhs.0, |l, r| l + r)))
     }
 }
 
@@ -63,7 +107,7 @@ impl Sub for FVec3 {
 
     #[doc(alias = "FVec3_Subtract")]
     fn sub(self, rhs: Self) -> Self::Output {
-        Self(unsafe { citro3d_sys::FVec3_Subtract(self.0, rhs.0) })
+        Self(without_w(zip_components(self.0, rhs.0, |l, r| l - r)))
     }
 }
 
@@ -72,7 +116,7 @@ impl Neg for FVec3 {
 
     #[doc(alias = "FVec3_Negate")]
     fn neg(self) -> Self::Output {
-        Self(unsafe { citro3d_sys::FVec3_Negate(self.0) })
+        Self(without_w(map_components(self.0, |c| -c)))
     }
 }
 
@@ -81,7 +125,62 @@ impl Mul<f32> for FVec3 {
 
     #[doc(alias = "FVec3_Scale")]
     fn mul(self, rhs: f32) -> Self::Output {
-        Self(unsafe { citro3d_sys::FVec3_Scale(self.0, rhs) })
+        Self(without_w(map_components(self.0, |c| c * rhs)))
+    }
+}
+
+// endregion
+
+// region: FQuat math operators
+
+impl Mul for FQuat {
+    type Output = Self;
+
+    /// Compose two rotations. The resulting quaternion represents a rotation
+    /// by `rhs` followed by a rotation by `self`.
+    #[doc(alias = "Quat_Multiply")]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(unsafe { citro3d_sys::Quat_Multiply(self.0, rhs.0) })
+    }
+}
+
+impl Mul<FVec3> for FQuat {
+    type Output = FVec3;
+
+    /// Rotate a vector by the rotation this quaternion represents. For a
+    /// composed rotation `(a * b) * v`, `v` is rotated by `b` first, then `a`.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use std::f32::consts::FRAC_PI_2;
+    /// # use citro3d::math::{FQuat, FVec3};
+    /// # use approx::assert_abs_diff_eq;
+    /// let q = FQuat::from_axis_angle(FVec3::UNIT_Z, FRAC_PI_2);
+    /// assert_abs_diff_eq!(q * FVec3::UNIT_X, FVec3::UNIT_Y);
+    /// ```
+    #[doc(alias = "Quat_CrossFVec3")]
+    #[doc(alias = "rotate_vector")]
+    fn mul(self, rhs: FVec3) -> Self::Output {
+        FVec(unsafe { citro3d_sys::Quat_CrossFVec3(self.0, rhs.0) })
+    }
+}
+
+impl Neg for FQuat {
+    type Output = Self;
+
+    #[doc(alias = "Quat_Negate")]
+    fn neg(self) -> Self::Output {
+        Self(map_components(self.0, |c| -c))
+    }
+}
+
+impl Mul<f32> for FQuat {
+    type Output = Self;
+
+    #[doc(alias = "Quat_Scale")]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self(map_components(self.0, |c| c * rhs))
     }
 }
 
@@ -98,6 +197,104 @@ where
     }
 }
 
+impl<const N: usize> Mul for FVec<N> {
+    type Output = Self;
+
+    /// Component-wise multiplication of two vectors.
+    fn mul(mut self, rhs: Self) -> Self::Output {
+        // Only touch the components in use, so an FVec3's W is left alone
+        for i in (4 - N)..4 {
+            unsafe { self.0.c[i] *= rhs.0.c[i] };
+        }
+        self
+    }
+}
+
+impl<const N: usize> Div for FVec<N> {
+    type Output = Self;
+
+    /// Component-wise division of two vectors. Dividing by a zero component
+    /// follows the usual `f32` rules, resulting in an infinite or NaN component.
+    fn div(mut self, rhs: Self) -> Self::Output {
+        for i in (4 - N)..4 {
+            unsafe { self.0.c[i] /= rhs.0.c[i] };
+        }
+        self
+    }
+}
+
+impl<Rhs, const N: usize> AddAssign<Rhs> for FVec<N>
+where
+    FVec<N>: Add<Rhs, Output = Self>,
+{
+    /// In-place addition, e.g. for updating a position every frame.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::FVec3;
+    /// # use approx::assert_abs_diff_eq;
+    /// let mut position = FVec3::zero();
+    /// let velocity = FVec3::new(0.5, 0.0, -1.0);
+    /// for _ in 0..4 {
+    ///     position += velocity;
+    /// }
+    /// assert_abs_diff_eq!(position, FVec3::new(2.0, 0.0, -4.0));
+    /// ```
+    fn add_assign(&mut self, rhs: Rhs) {
+        *self = *self + rhs;
+    }
+}
+
+impl<Rhs, const N: usize> SubAssign<Rhs> for FVec<N>
+where
+    FVec<N>: Sub<Rhs, Output = Self>,
+{
+    fn sub_assign(&mut self, rhs: Rhs) {
+        *self = *self - rhs;
+    }
+}
+
+impl<Rhs, const N: usize> MulAssign<Rhs> for FVec<N>
+where
+    FVec<N>: Mul<Rhs, Output = Self>,
+{
+    fn mul_assign(&mut self, rhs: Rhs) {
+        *self = *self * rhs;
+    }
+}
+
+impl<Rhs, const N: usize> DivAssign<Rhs> for FVec<N>
+where
+    FVec<N>: Div<Rhs, Output = Self>,
+{
+    fn div_assign(&mut self, rhs: Rhs) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const N: usize> Index<usize> for FVec<N> {
+    type Output = f32;
+
+    /// Index into the vector's components, in XYZW order.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds for the vector's size.
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(index < N, "index {index} out of bounds for FVec<{N}>");
+        // Components are stored in WZYX order, so index from the back
+        unsafe { &self.0.c[3 - index] }
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for FVec<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        assert!(index < N, "index {index} out of bounds for FVec<{N}>");
+        unsafe { &mut self.0.c[3 - index] }
+    }
+}
+
 impl<const N: usize> PartialEq for FVec<N> {
     fn eq(&self, other: &Self) -> bool {
         let range = (4 - N)..;
@@ -107,14 +304,33 @@ impl<const N: usize> PartialEq for FVec<N> {
 
 impl<const N: usize> Eq for FVec<N> {}
 
+/// Hashes the bitwise representation of the vector's components, so that it
+/// can be used as e.g. a [`HashMap`](std::collections::HashMap) key.
+///
+/// `0.0` and `-0.0` hash identically since they compare equal, but note that
+/// vectors containing NaN never compare equal, even to themselves, so they
+/// can't be looked up again once inserted.
+impl<const N: usize> Hash for FVec<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let range = (4 - N)..;
+        hash_floats(unsafe { &self.0.c[range] }, state);
+    }
+}
+
+/// The default tolerance for the `approx` traits, i.e. `f32::EPSILON.sqrt()`
+/// (which isn't a `const fn`). See
+/// <https://docs.rs/almost/latest/almost/#why-another-crate> for the rationale
+/// of using this over just `f32::EPSILON`.
+#[cfg(feature = "approx")]
+const DEFAULT_EPSILON: f32 = 3.452_669_7e-4;
+
 #[cfg(feature = "approx")]
+#[doc(cfg(feature = "approx"))]
 impl<const N: usize> AbsDiffEq for FVec<N> {
     type Epsilon = f32;
 
     fn default_epsilon() -> Self::Epsilon {
-        // See https://docs.rs/almost/latest/almost/#why-another-crate
-        // for rationale of using this over just EPSILON
-        f32::EPSILON.sqrt()
+        DEFAULT_EPSILON
     }
 
     fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
@@ -124,6 +340,93 @@ impl<const N: usize> AbsDiffEq for FVec<N> {
     }
 }
 
+#[cfg(feature = "approx")]
+#[doc(cfg(feature = "approx"))]
+impl<const N: usize> RelativeEq for FVec<N> {
+    fn default_max_relative() -> Self::Epsilon {
+        DEFAULT_EPSILON
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        let range = (4 - N)..;
+        let (lhs, rhs) = unsafe { (&self.0.c[range.clone()], &other.0.c[range]) };
+        lhs.relative_eq(rhs, epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "approx")]
+#[doc(cfg(feature = "approx"))]
+impl<const N: usize> UlpsEq for FVec<N> {
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        let range = (4 - N)..;
+        let (lhs, rhs) = unsafe { (&self.0.c[range.clone()], &other.0.c[range]) };
+        lhs.ulps_eq(rhs, epsilon, max_ulps)
+    }
+}
+
+impl PartialEq for FQuat {
+    fn eq(&self, other: &Self) -> bool {
+        unsafe { self.0.c == other.0.c }
+    }
+}
+
+impl Eq for FQuat {}
+
+#[cfg(feature = "approx")]
+#[doc(cfg(feature = "approx"))]
+impl AbsDiffEq for FQuat {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> Self::Epsilon {
+        DEFAULT_EPSILON
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        let (lhs, rhs) = unsafe { (&self.0.c, &other.0.c) };
+        lhs[..].abs_diff_eq(&rhs[..], epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+#[doc(cfg(feature = "approx"))]
+impl RelativeEq for FQuat {
+    fn default_max_relative() -> Self::Epsilon {
+        DEFAULT_EPSILON
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        let (lhs, rhs) = unsafe { (&self.0.c, &other.0.c) };
+        lhs[..].relative_eq(&rhs[..], epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "approx")]
+#[doc(cfg(feature = "approx"))]
+impl UlpsEq for FQuat {
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        let (lhs, rhs) = unsafe { (&self.0.c, &other.0.c) };
+        lhs[..].ulps_eq(&rhs[..], epsilon, max_ulps)
+    }
+}
+
 // region: Matrix math operators
 
 impl<Rhs: Borrow<Self>, const M: usize, const N: usize> Add<Rhs> for &Matrix<M, N> {
@@ -131,11 +434,8 @@ impl<Rhs: Borrow<Self>, const M: usize, const N: usize> Add<Rhs> for &Matrix<M,
 
     #[doc(alias = "Mtx_Add")]
     fn add(self, rhs: Rhs) -> Self::Output {
-        let mut out = MaybeUninit::uninit();
-        unsafe {
-            citro3d_sys::Mtx_Add(out.as_mut_ptr(), self.as_raw(), rhs.borrow().as_raw());
-            Matrix::new(out.assume_init())
-        }
+        let (lhs, rhs) = unsafe { (&*self.as_raw(), &*rhs.borrow().as_raw()) };
+        Matrix::new(zip_elements(lhs, rhs, |l, r| l + r))
     }
 }
 
@@ -144,11 +444,24 @@ impl<Rhs: Borrow<Self>, const M: usize, const N: usize> Sub<Rhs> for &Matrix<M,
 
     #[doc(alias = "Mtx_Subtract")]
     fn sub(self, rhs: Rhs) -> Self::Output {
-        let mut out = MaybeUninit::uninit();
-        unsafe {
-            citro3d_sys::Mtx_Subtract(out.as_mut_ptr(), self.as_raw(), rhs.borrow().as_raw());
-            Matrix::new(out.assume_init())
-        }
+        let (lhs, rhs) = unsafe { (&*self.as_raw(), &*rhs.borrow().as_raw()) };
+        Matrix::new(zip_elements(lhs, rhs, |l, r| l - r))
+    }
+}
+
+impl<Rhs: Borrow<Self>, const M: usize, const N: usize> AddAssign<Rhs> for Matrix<M, N> {
+    #[doc(alias = "Mtx_Add")]
+    fn add_assign(&mut self, rhs: Rhs) {
+        let (lhs, rhs) = unsafe { (&*self.as_raw(), &*rhs.borrow().as_raw()) };
+        *self = Matrix::new(zip_elements(lhs, rhs, |l, r| l + r));
+    }
+}
+
+impl<Rhs: Borrow<Self>, const M: usize, const N: usize> SubAssign<Rhs> for Matrix<M, N> {
+    #[doc(alias = "Mtx_Subtract")]
+    fn sub_assign(&mut self, rhs: Rhs) {
+        let (lhs, rhs) = unsafe { (&*self.as_raw(), &*rhs.borrow().as_raw()) };
+        *self = Matrix::new(zip_elements(lhs, rhs, |l, r| l - r));
     }
 }
 
@@ -169,7 +482,86 @@ impl<const M: usize, const N: usize, const P: usize> Mul<Matrix<N, P>> for &Matr
     type Output = Matrix<M, P>;
 
     fn mul(self, rhs: Matrix<N, P>) -> Self::Output {
-        self * &rhs
+        Mul::mul(self, &rhs)
+    }
+}
+
+impl<const M: usize, const N: usize, const P: usize> Mul<&Matrix<N, P>> for Matrix<M, N> {
+    type Output = Matrix<M, P>;
+
+    fn mul(self, rhs: &Matrix<N, P>) -> Self::Output {
+        Mul::mul(&self, rhs)
+    }
+}
+
+impl<const M: usize, const N: usize, const P: usize> Mul<Matrix<N, P>> for Matrix<M, N> {
+    type Output = Matrix<M, P>;
+
+    fn mul(self, rhs: Matrix<N, P>) -> Self::Output {
+        Mul::mul(&self, &rhs)
+    }
+}
+
+impl<Rhs: Borrow<Self>, const N: usize> MulAssign<Rhs> for Matrix<N, N> {
+    #[doc(alias = "Mtx_Multiply")]
+    fn mul_assign(&mut self, rhs: Rhs) {
+        // Mtx_Multiply doesn't allow its output to alias either input, so
+        // multiply into a temporary instead
+        *self = *self * rhs.borrow();
+    }
+}
+
+impl<const M: usize, const N: usize> Mul<f32> for &Matrix<M, N> {
+    type Output = Matrix<M, N>;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        let mut out = *self;
+        for row in 0..M {
+            for col in 0..N {
+                out[(row, col)] *= rhs;
+            }
+        }
+        out
+    }
+}
+
+impl<const M: usize, const N: usize> Mul<f32> for Matrix<M, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Mul::mul(&self, rhs)
+    }
+}
+
+impl<const M: usize, const N: usize> Div<f32> for &Matrix<M, N> {
+    type Output = Matrix<M, N>;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        self * (1.0 / rhs)
+    }
+}
+
+impl<const M: usize, const N: usize> Div<f32> for Matrix<M, N> {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        Div::div(&self, rhs)
+    }
+}
+
+impl<const M: usize, const N: usize> Neg for &Matrix<M, N> {
+    type Output = Matrix<M, N>;
+
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+impl<const M: usize, const N: usize> Neg for Matrix<M, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Neg::neg(&self)
     }
 }
 
@@ -200,8 +592,69 @@ impl Mul<FVec3> for &Matrix<4, 3> {
     }
 }
 
+impl Mul<FVec3> for Matrix3 {
+    type Output = FVec3;
+
+    fn mul(self, rhs: FVec3) -> Self::Output {
+        Mul::mul(&self, rhs)
+    }
+}
+
+impl Mul<FVec4> for Matrix4 {
+    type Output = FVec4;
+
+    fn mul(self, rhs: FVec4) -> Self::Output {
+        Mul::mul(&self, rhs)
+    }
+}
+
+impl Mul<FVec3> for Matrix<4, 3> {
+    type Output = FVec4;
+
+    fn mul(self, rhs: FVec3) -> Self::Output {
+        Mul::mul(&self, rhs)
+    }
+}
+
 // endregion
 
+impl<const M: usize, const N: usize> Index<(usize, usize)> for Matrix<M, N> {
+    type Output = f32;
+
+    /// Index into the matrix with a `(row, column)` pair.
+    ///
+    /// # Panics
+    ///
+    /// If `row` or `column` is out of bounds for the matrix's size.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::Matrix4;
+    /// let mut m = Matrix4::identity();
+    /// m.translate(1.0, 2.0, 3.0);
+    /// assert_eq!(m[(0, 3)], 1.0);
+    /// ```
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(
+            row < M && col < N,
+            "index ({row}, {col}) out of bounds for Matrix<{M}, {N}>"
+        );
+        // Rows are stored in WZYX order, so index columns from the back
+        unsafe { &(*self.as_raw()).r[row].c[3 - col] }
+    }
+}
+
+impl<const M: usize, const N: usize> IndexMut<(usize, usize)> for Matrix<M, N> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            row < M && col < N,
+            "index ({row}, {col}) out of bounds for Matrix<{M}, {N}>"
+        );
+        unsafe { &mut (*self.as_mut()).r[row].c[3 - col] }
+    }
+}
+
 impl<Rhs: Borrow<Self>, const M: usize, const N: usize> PartialEq<Rhs> for Matrix<M, N> {
     fn eq(&self, other: &Rhs) -> bool {
         self.as_rows() == other.borrow().as_rows()
@@ -210,15 +663,33 @@ impl<Rhs: Borrow<Self>, const M: usize, const N: usize> PartialEq<Rhs> for Matri
 
 impl<const M: usize, const N: usize> Eq for Matrix<M, N> {}
 
+/// Hashes the bitwise representation of the matrix's elements, so that it
+/// can be used as e.g. a [`HashMap`](std::collections::HashMap) key.
+///
+/// As with [`FVec`], matrices containing NaN never compare equal, so they
+/// can't be looked up again once inserted.
+impl<const M: usize, const N: usize> Hash for Matrix<M, N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_floats(self.as_rows().as_flattened(), state);
+    }
+}
+
+/// Hash a slice of floats consistently with their [`PartialEq`] impl.
+fn hash_floats<H: Hasher>(values: &[f32], state: &mut H) {
+    for &value in values {
+        // `0.0 == -0.0`, so they need to hash the same way too
+        let bits = if value == 0.0 { 0 } else { value.to_bits() };
+        bits.hash(state);
+    }
+}
+
 #[cfg(feature = "approx")]
 #[doc(cfg(feature = "approx"))]
 impl<const M: usize, const N: usize> AbsDiffEq for Matrix<M, N> {
     type Epsilon = f32;
 
     fn default_epsilon() -> Self::Epsilon {
-        // See https://docs.rs/almost/latest/almost/#why-another-crate
-        // for rationale of using this over just EPSILON
-        f32::EPSILON.sqrt()
+        DEFAULT_EPSILON
     }
 
     fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
@@ -237,12 +708,87 @@ impl<const M: usize, const N: usize> AbsDiffEq for Matrix<M, N> {
     }
 }
 
+#[cfg(feature = "approx")]
+#[doc(cfg(feature = "approx"))]
+impl<const M: usize, const N: usize> RelativeEq for Matrix<M, N> {
+    fn default_max_relative() -> Self::Epsilon {
+        DEFAULT_EPSILON
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        let lhs = self.as_rows();
+        let rhs = other.as_rows();
+        lhs.as_flattened()
+            .relative_eq(rhs.as_flattened(), epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "approx")]
+#[doc(cfg(feature = "approx"))]
+impl<const M: usize, const N: usize> UlpsEq for Matrix<M, N> {
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        let lhs = self.as_rows();
+        let rhs = other.as_rows();
+        lhs.as_flattened()
+            .ulps_eq(rhs.as_flattened(), epsilon, max_ulps)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use approx::assert_abs_diff_eq;
+    use std::f32::consts::FRAC_PI_2;
+
+    use approx::{assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq};
 
     use super::*;
 
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn fvec_hash() {
+        let v = FVec3::new(1.0, 2.0, 3.0);
+        assert_eq!(hash_of(&v), hash_of(&FVec3::new(1.0, 2.0, 3.0)));
+        assert_ne!(hash_of(&v), hash_of(&FVec3::new(3.0, 2.0, 1.0)));
+
+        // Only significant components are hashed
+        let mut padded = v;
+        unsafe { padded.0.c[0] = 42.0 };
+        assert_eq!(v, padded);
+        assert_eq!(hash_of(&v), hash_of(&padded));
+
+        assert_eq!(FVec4::splat(0.0), FVec4::splat(-0.0));
+        assert_eq!(hash_of(&FVec4::splat(0.0)), hash_of(&FVec4::splat(-0.0)));
+    }
+
+    #[test]
+    fn matrix_hash() {
+        let mut a = Matrix4::identity();
+        a.translate(1.0, 2.0, 3.0);
+        let b = a;
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert_ne!(hash_of(&a), hash_of(&Matrix4::identity()));
+
+        let mut cache = std::collections::HashMap::new();
+        cache.insert(a, "translated");
+        assert_eq!(cache.get(&b), Some(&"translated"));
+    }
+
     #[test]
     fn fvec3() {
         let l = FVec3::splat(1.0);
@@ -265,6 +811,154 @@ mod tests {
         assert_abs_diff_eq!(-l, FVec4::splat(-1.0));
         assert_abs_diff_eq!(l * 1.5, FVec4::splat(1.5));
         assert_abs_diff_eq!(l / 2.0, FVec4::splat(0.5));
+
+        // Values of very different magnitudes need a relative comparison
+        let v = FVec4::new(0.001, 400.0, -2.0, 1.0e6);
+        assert_relative_eq!(v * 3.0 / 3.0, v);
+        assert_ulps_eq!(v + FVec4::splat(0.0), v);
+        assert!(!approx::relative_eq!(v, v * 1.01));
+    }
+
+    #[test]
+    fn default_epsilon() {
+        assert_eq!(DEFAULT_EPSILON, f32::EPSILON.sqrt());
+        assert_eq!(FVec4::default_epsilon(), DEFAULT_EPSILON);
+        assert_eq!(Matrix4::default_max_relative(), DEFAULT_EPSILON);
+    }
+
+    #[test]
+    fn fvec_relative_eq() {
+        // Large transformed coordinates are off by far more than the default
+        // absolute epsilon, but are still within a small relative tolerance
+        let l = FVec3::new(1.0e5, -2.5e5, 3.0e6);
+        let r = FVec3::new(1.0e5 + 1.0, -2.5e5 - 2.0, 3.0e6 + 16.0);
+        assert!(!approx::abs_diff_eq!(l, r));
+        assert_relative_eq!(l, r, max_relative = 1.0e-4);
+        assert_ulps_eq!(l, FVec3::new(1.0e5, -2.5e5, 3.0e6 + 0.25), max_ulps = 4);
+
+        // The unused W component of an FVec3 is ignored
+        let mut w = l;
+        w.0.__bindgen_anon_1.w = 1.0e9;
+        assert_relative_eq!(l, w);
+        assert_ulps_eq!(l, w);
+    }
+
+    #[test]
+    fn fvec_assign() {
+        let l = FVec3::new(1.0, 2.0, 3.0);
+        let r = FVec3::new(-1.0, 0.5, 2.0);
+
+        let mut v = l;
+        v += r;
+        assert_abs_diff_eq!(v, l + r);
+
+        let mut v = l;
+        v -= r;
+        assert_abs_diff_eq!(v, l - r);
+
+        let mut v = l;
+        v *= 1.5;
+        assert_abs_diff_eq!(v, l * 1.5);
+
+        let mut v = l;
+        v /= 2.0;
+        assert_abs_diff_eq!(v, l / 2.0);
+
+        let l = FVec4::new(1.0, 2.0, 3.0, 4.0);
+        let r = FVec4::new(-1.0, 0.5, 2.0, 8.0);
+
+        let mut v = l;
+        v += r;
+        assert_abs_diff_eq!(v, l + r);
+
+        let mut v = l;
+        v -= r;
+        assert_abs_diff_eq!(v, l - r);
+
+        let mut v = l;
+        v *= r;
+        assert_abs_diff_eq!(v, l * r);
+
+        let mut v = l;
+        v /= r;
+        assert_abs_diff_eq!(v, l / r);
+    }
+
+    #[test]
+    fn fvec_component_wise() {
+        let l = FVec3::new(1.0, -2.0, 3.0);
+        let r = FVec3::new(2.0, 4.0, -0.5);
+        assert_abs_diff_eq!(l * r, FVec3::new(2.0, -8.0, -1.5));
+        assert_abs_diff_eq!(l / r, FVec3::new(0.5, -0.5, -6.0));
+        // The unused W component should be left as-is
+        assert_eq!(unsafe { (l / r).0.__bindgen_anon_1.w }, 0.0);
+
+        let l = FVec4::new(1.0, -2.0, 3.0, 4.0);
+        let r = FVec4::new(2.0, 4.0, -0.5, 8.0);
+        assert_abs_diff_eq!(l * r, FVec4::new(2.0, -8.0, -1.5, 32.0));
+        assert_abs_diff_eq!(l / r, FVec4::new(0.5, -0.5, -6.0, 0.5));
+
+        let v = l / FVec4::new(0.0, 0.0, 1.0, 1.0);
+        assert_eq!(v.x(), f32::INFINITY);
+        assert_eq!(v.y(), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn fvec_index() {
+        let mut v = FVec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!([v[0], v[1], v[2], v[3]], [1.0, 2.0, 3.0, 4.0]);
+
+        v[0] = 5.0;
+        v[3] *= 2.0;
+        assert_eq!(v, FVec4::new(5.0, 2.0, 3.0, 8.0));
+
+        let mut v = FVec3::new(1.0, 2.0, 3.0);
+        for i in 0..3 {
+            v[i] += 1.0;
+        }
+        assert_eq!(v, FVec3::new(2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn fquat() {
+        let q = FQuat::new(1.0, 2.0, 3.0, 4.0);
+        let identity = FQuat::identity();
+
+        assert_eq!(q * identity, q);
+        assert_eq!(identity * q, q);
+        assert_eq!(-q, FQuat::new(-1.0, -2.0, -3.0, -4.0));
+        assert_eq!(q * 2.0, FQuat::new(2.0, 4.0, 6.0, 8.0));
+
+        // i * j = k, but j * i = -k
+        let i = FQuat::new(1.0, 0.0, 0.0, 0.0);
+        let j = FQuat::new(0.0, 1.0, 0.0, 0.0);
+        assert_eq!(i * j, FQuat::new(0.0, 0.0, 1.0, 0.0));
+        assert_eq!(j * i, FQuat::new(0.0, 0.0, -1.0, 0.0));
+
+        assert_relative_eq!(q * 1.0e4 * 1.0e-4, q);
+        assert_ulps_eq!(-(-q), q);
+    }
+
+    #[test]
+    fn fquat_rotate_vector() {
+        let z = FQuat::from_axis_angle(FVec3::UNIT_Z, FRAC_PI_2);
+        let y = FQuat::from_axis_angle(FVec3::UNIT_Y, FRAC_PI_2);
+
+        assert_abs_diff_eq!(z * FVec3::UNIT_X, FVec3::UNIT_Y);
+        assert_abs_diff_eq!(y * FVec3::UNIT_X, -FVec3::UNIT_Z);
+
+        // The right-hand side is applied first: X rotated about Z is Y, which
+        // is unchanged by rotating about Y
+        assert_abs_diff_eq!((y * z) * FVec3::UNIT_X, FVec3::UNIT_Y);
+        assert_abs_diff_eq!((y * z) * FVec3::UNIT_X, y * (z * FVec3::UNIT_X));
+        // Whereas X rotated about Y is -Z, which is unchanged by rotating about Z
+        assert_abs_diff_eq!((z * y) * FVec3::UNIT_X, -FVec3::UNIT_Z);
+
+        // Rotating a vector agrees with the equivalent matrix
+        let v = FVec3::new(1.0, -2.0, 3.0);
+        let q = (y * z).rotate_x(0.3);
+        assert_abs_diff_eq!(q * v, q.to_matrix().transform_vector(v), epsilon = 1.0e-5);
+        assert_abs_diff_eq!((q * v).magnitude(), v.magnitude(), epsilon = 1.0e-5);
     }
 
     #[test]
@@ -276,6 +970,230 @@ mod tests {
         assert_abs_diff_eq!(&(l * r), l);
         assert_abs_diff_eq!(&(l + r), &Matrix3::diagonal(2.0, 3.0, 4.0));
         assert_abs_diff_eq!(&(l - r), &Matrix3::diagonal(0.0, 1.0, 2.0));
+
+        assert_eq!(*l * *r, l * r);
+        assert_eq!(*l * r, l * r);
+
+        let v = FVec3::new(1.0, 1.0, 1.0);
+        assert_eq!(*l * v, l * v);
+        assert_eq!(*l * v, FVec3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn matrix_assign() {
+        let l = Matrix4::diagonal(1.0, 2.0, 3.0, 4.0);
+        let r = Matrix4::identity();
+
+        let mut m = l;
+        m += &r;
+        assert_abs_diff_eq!(m, &l + &r);
+
+        let mut m = l;
+        m -= &r;
+        assert_abs_diff_eq!(m, &l - &r);
+
+        let l = Matrix3::diagonal(1.0, 2.0, 3.0);
+        let mut m = l;
+        m += l;
+        assert_abs_diff_eq!(m, Matrix3::diagonal(2.0, 4.0, 6.0));
+
+        // Assigning should give the same result as the binary operators
+        let l = Matrix4::from_rows([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+            [13.0, 14.0, 15.0, 16.0],
+        ]);
+        let r = Matrix4::from_rows([
+            [0.5, -1.0, 2.0, 0.0],
+            [3.0, 0.25, -4.0, 1.0],
+            [-2.0, 6.0, 1.5, -3.0],
+            [7.0, 0.0, -0.5, 2.0],
+        ]);
+
+        let mut m = l;
+        m += &r;
+        assert_eq!(m, &l + &r);
+
+        let mut m = l;
+        m -= &r;
+        assert_eq!(m, &l - &r);
+
+        let mut m = l;
+        m -= l;
+        assert_eq!(m, Matrix4::zero());
+    }
+
+    #[test]
+    fn matrix_index() {
+        let m = Matrix4::identity();
+        for row in 0..4 {
+            for col in 0..4 {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert_eq!(m[(row, col)], expected);
+            }
+        }
+
+        let mut m = Matrix4::identity();
+        m.translate(1.0, 2.0, 3.0);
+        assert_eq!(m[(0, 3)], 1.0);
+        assert_eq!(m[(1, 3)], 2.0);
+        assert_eq!(m[(2, 3)], 3.0);
+        assert_eq!(m[(3, 0)], 0.0);
+
+        let mut m = Matrix3::identity();
+        m[(0, 2)] = 5.0;
+        m[(2, 0)] = -1.0;
+        assert_eq!(m[(0, 2)], 5.0);
+        assert_eq!(m.as_rows()[0], [5.0, 0.0, 1.0]);
+        assert_eq!(m.as_rows()[2], [1.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    fn matrix_mul_assign() {
+        let mut x = Matrix4::identity();
+        x.rotate_x(0.3);
+        let mut y = Matrix4::identity();
+        y.rotate_y(-1.2);
+        let mut z = Matrix4::identity();
+        z.rotate_z(2.0);
+
+        let mut m = Matrix4::diagonal(1.0, 2.0, 3.0, 1.0);
+        let expected = m * x * y * z;
+        m *= &x;
+        m *= &y;
+        m *= z;
+        assert_abs_diff_eq!(m, expected);
+
+        let mut m = Matrix3::diagonal(1.0, 2.0, 3.0);
+        m *= m;
+        assert_abs_diff_eq!(m, Matrix3::diagonal(1.0, 4.0, 9.0));
+    }
+
+    #[test]
+    fn matrix_scalar() {
+        let mut m = Matrix4::identity();
+        m.rotate_x(0.5);
+        m.translate(1.0, -2.0, 3.0);
+
+        assert_eq!(&m * 2.0, &m + &m);
+        assert_eq!(m * 2.0, &m + &m);
+        assert_abs_diff_eq!(&(&m + &m) / 2.0, &m);
+        assert_abs_diff_eq!((&m + &m) / 2.0, m);
+        assert_eq!(-&m, &Matrix4::zero() - &m);
+        assert_eq!(-(-m), m);
+
+        let m = Matrix::<4, 3>::from([
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+            [10.0, 11.0, 12.0],
+        ]);
+        assert_eq!(&m * 3.0, &(&m + &m) + &m);
+        assert_eq!(
+            (-m).to_rows(),
+            [
+                [-1.0, -2.0, -3.0],
+                [-4.0, -5.0, -6.0],
+                [-7.0, -8.0, -9.0],
+                [-10.0, -11.0, -12.0],
+            ]
+        );
+    }
+
+    /// Deterministic pseudo-random floats for comparing against the C
+    /// implementations, mixing magnitudes and some special values.
+    fn random_floats(seed: u32) -> impl Iterator<Item = f32> {
+        const SPECIAL: [f32; 6] = [0.0, -0.0, 1.0, f32::MIN_POSITIVE, f32::MAX, f32::INFINITY];
+
+        let mut state = seed;
+        std::iter::repeat_with(move || {
+            // xorshift32
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        })
+        .map(|bits| match bits % 16 {
+            0 => SPECIAL[(bits >> 4) as usize % SPECIAL.len()],
+            1..=7 => f32::from_bits(bits & !(0xFF << 23) | (0x7F - 20 + (bits >> 24) % 40) << 23),
+            _ => (bits >> 8) as f32 / (1 << 16) as f32 - 128.0,
+        })
+    }
+
+    fn bits(v: C3D_FVec) -> [u32; 4] {
+        unsafe { v.c }.map(f32::to_bits)
+    }
+
+    #[test]
+    fn native_ops_match_ffi() {
+        let mut floats = random_floats(0x3D5);
+        let mut next_vec = || C3D_FVec {
+            c: std::array::from_fn(|_| floats.next().unwrap()),
+        };
+
+        for _ in 0..1000 {
+            let (l, r, s) = (next_vec(), next_vec(), next_vec());
+            let s = unsafe { s.c[0] };
+            let (l4, r4) = (FVec4::from_raw(l), FVec4::from_raw(r));
+            let (l3, r3) = (FVec3::from_raw(l), FVec3::from_raw(r));
+
+            unsafe {
+                use citro3d_sys::*;
+
+                assert_eq!(bits((l4 + r4).0), bits(FVec4_Add(l, r)));
+                assert_eq!(bits((l4 - r4).0), bits(FVec4_Subtract(l, r)));
+                assert_eq!(bits((-l4).0), bits(FVec4_Negate(l)));
+                assert_eq!(bits((l4 * s).0), bits(FVec4_Scale(l, s)));
+                assert_eq!(l4.dot(r4).to_bits(), FVec4_Dot(l, r).to_bits());
+
+                assert_eq!(bits((l3 + r3).0), bits(FVec3_Add(l, r)));
+                assert_eq!(bits((l3 - r3).0), bits(FVec3_Subtract(l, r)));
+                assert_eq!(bits((-l3).0), bits(FVec3_Negate(l)));
+                assert_eq!(bits((l3 * s).0), bits(FVec3_Scale(l, s)));
+                assert_eq!(l3.dot(r3).to_bits(), FVec3_Dot(l, r).to_bits());
+                assert_eq!(bits(l3.cross(r3).0), bits(FVec3_Cross(l, r)));
+            }
+        }
+
+        let mut next_mtx = || C3D_Mtx {
+            m: std::array::from_fn(|_| floats.next().unwrap()),
+        };
+
+        for _ in 0..100 {
+            let (l, r) = (next_mtx(), next_mtx());
+            let (lm, rm) = unsafe { (Matrix4::from_raw(l), Matrix4::from_raw(r)) };
+
+            let mut expected = MaybeUninit::uninit();
+            unsafe { citro3d_sys::Mtx_Add(expected.as_mut_ptr(), &l, &r) };
+            let expected = unsafe { expected.assume_init().m }.map(f32::to_bits);
+            assert_eq!(
+                unsafe { (&lm + &rm).into_raw().m }.map(f32::to_bits),
+                expected
+            );
+
+            let mut expected = MaybeUninit::uninit();
+            unsafe { citro3d_sys::Mtx_Subtract(expected.as_mut_ptr(), &l, &r) };
+            let expected = unsafe { expected.assume_init().m }.map(f32::to_bits);
+            assert_eq!(
+                unsafe { (&lm - &rm).into_raw().m }.map(f32::to_bits),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn matrix_relative_eq() {
+        // Like a projection matrix, mix very small and large elements
+        let l = Matrix4::diagonal(0.001, 400.0, -2.0, 1.0e6);
+        let r = Matrix4::diagonal(0.001_000_01, 400.01, -2.0, 1.0e6 + 1.0);
+        assert_relative_eq!(l, r);
+        assert_ulps_eq!(l, l.clone());
+        assert!(!approx::relative_eq!(
+            l,
+            Matrix4::diagonal(0.002, 400.0, -2.0, 1.0e6)
+        ));
+        assert!(!approx::ulps_eq!(l, r));
     }
 
     #[test]
@@ -287,5 +1205,24 @@ mod tests {
         assert_abs_diff_eq!(&(l * r), l);
         assert_abs_diff_eq!(&(l + r), &Matrix4::diagonal(2.0, 3.0, 4.0, 5.0));
         assert_abs_diff_eq!(&(l - r), &Matrix4::diagonal(0.0, 1.0, 2.0, 3.0));
+
+        assert_eq!(*l * *r, l * r);
+        assert_eq!(*l * r, l * r);
+
+        let v = FVec4::new(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(*l * v, l * v);
+        assert_eq!(*l * v, FVec4::new(1.0, 2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn matrix_mul_chain() {
+        let projection = Matrix4::diagonal(2.0, 2.0, 1.0, 1.0);
+        let mut view = Matrix4::identity();
+        view.translate(0.0, 0.0, -5.0);
+        let mut model = Matrix4::identity();
+        model.rotate_y(1.0);
+
+        let expected = projection * (view * model);
+        assert_abs_diff_eq!(projection * view * model, expected);
     }
 }
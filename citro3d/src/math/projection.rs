@@ -9,6 +9,7 @@ use super::Matrix4;
 ///
 /// To use the resulting projection, convert it to a [`Matrix`](super::Matrix) with [`From`]/[`Into`].
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Projection<Kind> {
     coordinates: CoordinateOrientation,
     rotation: ScreenOrientation,
@@ -36,7 +37,7 @@ impl<Kind> Projection<Kind> {
     ///     near: 0.1,
     ///     far: 100.0,
     /// };
-    /// let mtx: Matrix4 = Projection::perspective(40.0,  AspectRatio::TopScreen, clip_planes)
+    /// let mtx: Matrix4 = Projection::perspective_degrees(40.0, AspectRatio::TopScreen, clip_planes)
     ///     .coordinates(CoordinateOrientation::LeftHanded)
     ///     .into();
     /// ```
@@ -57,7 +58,7 @@ impl<Kind> Projection<Kind> {
     ///     near: 0.1,
     ///     far: 100.0,
     /// };
-    /// let mtx: Matrix4 = Projection::perspective(40.0, AspectRatio::TopScreen, clip_planes)
+    /// let mtx: Matrix4 = Projection::perspective_degrees(40.0, AspectRatio::TopScreen, clip_planes)
     ///     .screen(ScreenOrientation::None)
     ///     .into();
     /// ```
@@ -65,10 +66,43 @@ impl<Kind> Projection<Kind> {
         self.rotation = orientation;
         self
     }
+
+    /// Calculate the projection matrix, without consuming the projection.
+    /// Equivalent to converting it with [`From`]/[`Into`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{AspectRatio, ClipPlanes, FVec3, Matrix4, Projection};
+    /// let projection = Projection::perspective_degrees(
+    ///     40.0,
+    ///     AspectRatio::TopScreen,
+    ///     ClipPlanes {
+    ///         near: 0.01,
+    ///         far: 100.0,
+    ///     },
+    /// );
+    ///
+    /// let view = Matrix4::from_translation(FVec3::new(0.0, 0.0, -5.0));
+    /// let model = Matrix4::identity();
+    /// let model_view_projection = projection.to_matrix() * view * model;
+    /// ```
+    pub fn to_matrix(&self) -> Matrix4
+    where
+        for<'a> Matrix4: From<&'a Self>,
+    {
+        Matrix4::from(self)
+    }
 }
 
 /// See [`Projection::perspective`].
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedPerspective")
+)]
 pub struct Perspective {
     vertical_fov_radians: f32,
     aspect_ratio: AspectRatio,
@@ -76,6 +110,32 @@ pub struct Perspective {
     stereo: Option<StereoDisplacement>,
 }
 
+/// The fields of a [`Perspective`], deserialized before they are validated.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedPerspective {
+    vertical_fov_radians: f32,
+    aspect_ratio: AspectRatio,
+    clip_planes: ClipPlanes,
+    stereo: Option<StereoDisplacement>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedPerspective> for Perspective {
+    type Error = String;
+
+    fn try_from(unchecked: UncheckedPerspective) -> Result<Self, Self::Error> {
+        unchecked.clip_planes.check_perspective()?;
+
+        Ok(Self {
+            vertical_fov_radians: unchecked.vertical_fov_radians,
+            aspect_ratio: unchecked.aspect_ratio,
+            clip_planes: unchecked.clip_planes,
+            stereo: unchecked.stereo,
+        })
+    }
+}
+
 impl Projection<Perspective> {
     /// Construct a projection matrix suitable for projecting 3D world space onto
     /// the 3DS screens.
@@ -88,6 +148,10 @@ impl Projection<Perspective> {
     ///   [`ClipPlanes`] are always defined by near and far values, regardless
     ///   of the projection's [`CoordinateOrientation`].
     ///
+    /// # Panics
+    ///
+    /// If `clip_planes.near` is not positive, or is not less than `clip_planes.far`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -109,11 +173,16 @@ impl Projection<Perspective> {
     /// ```
     #[doc(alias = "Mtx_Persp")]
     #[doc(alias = "Mtx_PerspTilt")]
+    #[doc(alias = "from_fov_radians")]
     pub fn perspective(
         vertical_fov_radians: f32,
         aspect_ratio: AspectRatio,
         clip_planes: ClipPlanes,
     ) -> Self {
+        if let Err(err) = clip_planes.check_perspective() {
+            panic!("{err}");
+        }
+
         Self::new(Perspective {
             vertical_fov_radians,
             aspect_ratio,
@@ -122,10 +191,42 @@ impl Projection<Perspective> {
         })
     }
 
+    /// Like [`Projection::perspective`], but with the vertical field of view
+    /// measured in degrees instead of radians.
+    ///
+    /// # Panics
+    ///
+    /// See [`Projection::perspective`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::{AspectRatio, ClipPlanes, Matrix4, Projection};
+    /// let clip_planes = ClipPlanes {
+    ///     near: 0.01,
+    ///     far: 100.0,
+    /// };
+    /// let mtx: Matrix4 =
+    ///     Projection::perspective_degrees(45.0, AspectRatio::TopScreen, clip_planes).into();
+    /// ```
+    #[doc(alias = "from_fov_degrees")]
+    pub fn perspective_degrees(
+        vertical_fov_degrees: f32,
+        aspect_ratio: AspectRatio,
+        clip_planes: ClipPlanes,
+    ) -> Self {
+        Self::perspective(vertical_fov_degrees.to_radians(), aspect_ratio, clip_planes)
+    }
+
     /// Helper function to build both eyes' perspective projection matrices
     /// at once. See [`StereoDisplacement`] for details on how to configure
     /// stereoscopy.
     ///
+    /// Like [`Projection::perspective`], the matrices are rotated for the 3DS
+    /// screen by default (using `Mtx_PerspStereoTilt`), unless disabled with
+    /// [`screen`](Projection::screen).
+    ///
     /// ```
     /// # use std::f32::consts::PI;
     /// # use citro3d::math::*;
@@ -150,12 +251,11 @@ impl Projection<Perspective> {
         left_eye: StereoDisplacement,
         right_eye: StereoDisplacement,
     ) -> (Matrix4, Matrix4) {
-        // TODO: we might be able to avoid this clone if there was a conversion
-        // from &Self to Matrix instead of Self... but it's probably fine for now
-        let left = self.clone().stereo(left_eye);
-        let right = self.stereo(right_eye);
+        let projection = self.stereo(left_eye);
+        let left = projection.to_matrix();
+        let projection = projection.stereo(right_eye);
         // Also, we could consider just returning (Self, Self) here? idk
-        (left.into(), right.into())
+        (left, projection.into())
     }
 
     fn stereo(mut self, displacement: StereoDisplacement) -> Self {
@@ -166,6 +266,12 @@ impl Projection<Perspective> {
 
 impl From<Projection<Perspective>> for Matrix4 {
     fn from(projection: Projection<Perspective>) -> Self {
+        Self::from(&projection)
+    }
+}
+
+impl From<&Projection<Perspective>> for Matrix4 {
+    fn from(projection: &Projection<Perspective>) -> Self {
         let Perspective {
             vertical_fov_radians,
             aspect_ratio,
@@ -215,6 +321,7 @@ impl From<Projection<Perspective>> for Matrix4 {
 
 /// See [`Projection::orthographic`].
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Orthographic {
     clip_planes_x: Range<f32>,
     clip_planes_y: Range<f32>,
@@ -229,6 +336,15 @@ impl Projection<Orthographic> {
     /// The Z [`ClipPlanes`], however, are always defined by
     /// near and far values, regardless of the projection's [`CoordinateOrientation`].
     ///
+    /// Like other projections, the resulting matrix is rotated to account for
+    /// the 3DS screen orientation by default (see [`ScreenOrientation::Rotated`]),
+    /// so the ranges should be given in terms of the screen as the user sees it.
+    /// Use [`screen`](Projection::screen) to disable this rotation.
+    ///
+    /// # Panics
+    ///
+    /// If `clip_planes_z.near` is not less than `clip_planes_z.far`.
+    ///
     /// # Example
     ///
     /// ```
@@ -252,6 +368,8 @@ impl Projection<Orthographic> {
         clip_planes_y: Range<f32>,
         clip_planes_z: ClipPlanes,
     ) -> Self {
+        clip_planes_z.validate();
+
         Self::new(Orthographic {
             clip_planes_x,
             clip_planes_y,
@@ -262,6 +380,12 @@ impl Projection<Orthographic> {
 
 impl From<Projection<Orthographic>> for Matrix4 {
     fn from(projection: Projection<Orthographic>) -> Self {
+        Self::from(&projection)
+    }
+}
+
+impl From<&Projection<Orthographic>> for Matrix4 {
+    fn from(projection: &Projection<Orthographic>) -> Self {
         let make_mtx = match projection.rotation {
             ScreenOrientation::Rotated => citro3d_sys::Mtx_OrthoTilt,
             ScreenOrientation::None => citro3d_sys::Mtx_Ortho,
@@ -271,7 +395,7 @@ impl From<Projection<Orthographic>> for Matrix4 {
             clip_planes_x,
             clip_planes_y,
             clip_planes_z,
-        } = projection.inner;
+        } = &projection.inner;
 
         let mut out = MaybeUninit::uninit();
         unsafe {
@@ -296,6 +420,7 @@ impl From<Projection<Orthographic>> for Matrix4 {
 /// (or "handedness") of the coordinate system. Coordinates are always +Y-up,
 /// +X-right.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CoordinateOrientation {
     /// A left-handed coordinate system. +Z points into the screen.
     LeftHanded,
@@ -323,12 +448,18 @@ impl Default for CoordinateOrientation {
 /// Both screens on the 3DS are oriented such that the "top-left" of the screen
 /// in framebuffer coordinates is the physical bottom-left of the screen
 /// (i.e. the "width" is smaller than the "height").
+///
+/// Use [`Projection::screen`] to choose the orientation of a projection.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScreenOrientation {
     /// Rotate 90° clockwise to account for the 3DS screen rotation. Most
     /// applications will use this variant.
     Rotated,
-    /// Do not apply any extra rotation to the projection.
+    /// Do not apply any extra rotation to the projection. This is useful when
+    /// rendering to an off-screen target such as a texture, which isn't
+    /// displayed on the physical screen directly. See also
+    /// [`Matrix4::perspective`] and [`Matrix4::orthographic`].
     None,
 }
 
@@ -341,6 +472,7 @@ impl Default for ScreenOrientation {
 /// Configuration for calculating stereoscopic projections.
 // TODO: not totally happy with this name + API yet, but it works for now.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StereoDisplacement {
     /// The horizontal offset of the eye from center. Negative values
     /// correspond to the left eye, and positive values to the right eye.
@@ -359,6 +491,18 @@ impl StereoDisplacement {
     ///
     /// See struct documentation for details about the
     /// [`screen_depth`](Self::screen_depth) parameter.
+    ///
+    /// Returns the displacements as a `(left, right)` pair, which can be
+    /// passed directly to [`Projection::stereo_matrices`].
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::StereoDisplacement;
+    /// let (left, right) = StereoDisplacement::new(0.5, 2.0);
+    /// assert_eq!(left.displacement, -0.25);
+    /// assert_eq!(right.displacement, 0.25);
+    /// ```
     pub fn new(interocular_distance: f32, screen_depth: f32) -> (Self, Self) {
         let displacement = interocular_distance.abs() / 2.0;
 
@@ -387,7 +531,15 @@ impl StereoDisplacement {
 /// [`CoordinateOrientation`]. In other words, these values will be negated
 /// when used with a [`RightHanded`](CoordinateOrientation::RightHanded)
 /// orientation.
+///
+/// When deserialized, the clip planes are checked the same way as by the
+/// projection constructors, failing instead of panicking if they are invalid.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UncheckedClipPlanes")
+)]
 pub struct ClipPlanes {
     /// The Z-depth of the near clip plane, usually close or equal to zero.
     pub near: f32,
@@ -395,8 +547,70 @@ pub struct ClipPlanes {
     pub far: f32,
 }
 
+impl ClipPlanes {
+    /// Panic if the clip planes would result in a degenerate projection.
+    fn validate(self) {
+        if let Err(err) = self.check() {
+            panic!("{err}");
+        }
+    }
+
+    /// Check that the clip planes would not result in a degenerate projection.
+    fn check(self) -> Result<(), String> {
+        if self.near < self.far {
+            Ok(())
+        } else {
+            Err(format!(
+                "near clip plane ({}) must be less than far clip plane ({})",
+                self.near, self.far,
+            ))
+        }
+    }
+
+    /// Like [`check`](Self::check), but also require the near clip plane to be
+    /// positive, as it must be for a perspective projection.
+    fn check_perspective(self) -> Result<(), String> {
+        self.check()?;
+        if self.near > 0.0 {
+            Ok(())
+        } else {
+            Err(format!(
+                "near clip plane must be positive for a perspective projection, got {}",
+                self.near,
+            ))
+        }
+    }
+}
+
+/// The fields of a [`ClipPlanes`], deserialized before they are validated.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedClipPlanes {
+    near: f32,
+    far: f32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedClipPlanes> for ClipPlanes {
+    type Error = String;
+
+    fn try_from(
+        UncheckedClipPlanes { near, far }: UncheckedClipPlanes,
+    ) -> Result<Self, Self::Error> {
+        let clip_planes = Self { near, far };
+        clip_planes.check()?;
+        Ok(clip_planes)
+    }
+}
+
 /// The aspect ratio of a projection plane.
+///
+/// For rendering to a whole screen, prefer the [`TopScreen`](Self::TopScreen)
+/// (400x240 per eye) and [`BottomScreen`](Self::BottomScreen) (320x240)
+/// variants. Note that these are the dimensions of the screens as the user sees
+/// them, not the rotated 240x400 and 240x320 framebuffers.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 #[doc(alias = "C3D_AspectRatioTop")]
 #[doc(alias = "C3D_AspectRatioBot")]
@@ -407,10 +621,27 @@ pub enum AspectRatio {
     /// The aspect ratio of the 3DS' bottom screen.
     #[doc(alias = "C3D_AspectRatioBot")]
     BottomScreen,
-    /// A custom aspect ratio (should be calcualted as `width / height`).
+    /// A custom aspect ratio (should be calculated as `width / height`).
     Other(f32),
 }
 
+impl AspectRatio {
+    /// Calculate the aspect ratio of a `width` by `height` projection plane,
+    /// e.g. a render target that doesn't cover a whole screen.
+    ///
+    /// # Example
+    /// ```
+    /// # let _runner = test_runner::GdbRunner::default();
+    /// # use citro3d::math::AspectRatio;
+    /// # use approx::assert_abs_diff_eq;
+    /// let ratio = AspectRatio::from_dimensions(400, 240);
+    /// assert_abs_diff_eq!(f32::from(ratio), f32::from(AspectRatio::TopScreen));
+    /// ```
+    pub fn from_dimensions(width: u32, height: u32) -> Self {
+        Self::Other(width as f32 / height as f32)
+    }
+}
+
 impl From<AspectRatio> for f32 {
     fn from(ratio: AspectRatio) -> Self {
         match ratio {
@@ -422,3 +653,258 @@ impl From<AspectRatio> for f32 {
 }
 
 // endregion
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    use approx::{assert_abs_diff_eq, assert_abs_diff_ne};
+
+    use super::*;
+
+    #[test]
+    fn to_matrix() {
+        let clip_planes = ClipPlanes {
+            near: 0.01,
+            far: 100.0,
+        };
+
+        let projection = Projection::perspective_degrees(40.0, AspectRatio::TopScreen, clip_planes);
+        let mtx = projection.to_matrix();
+        assert_eq!(mtx * Matrix4::identity(), mtx);
+        assert_eq!(mtx, Matrix4::from(projection));
+
+        let projection = Projection::orthographic(0.0..400.0, 0.0..240.0, clip_planes)
+            .coordinates(CoordinateOrientation::LeftHanded);
+        let mtx = projection.to_matrix();
+        assert_eq!(mtx * Matrix4::identity(), mtx);
+        assert_eq!(mtx, Matrix4::from(projection));
+    }
+
+    #[test]
+    fn perspective_degrees() {
+        let clip_planes = ClipPlanes {
+            near: 0.1,
+            far: 100.0,
+        };
+        let radians: Matrix4 =
+            Projection::perspective(FRAC_PI_4, AspectRatio::BottomScreen, clip_planes).into();
+        let degrees: Matrix4 =
+            Projection::perspective_degrees(45.0, AspectRatio::BottomScreen, clip_planes).into();
+        assert_abs_diff_eq!(radians, degrees);
+    }
+
+    #[test]
+    fn stereo_displacement() {
+        for iod in [0.5, -0.5] {
+            let (left, right) = StereoDisplacement::new(iod, 2.0);
+            assert!(left.displacement < 0.0);
+            assert!(right.displacement > 0.0);
+            assert_eq!(left.displacement, -right.displacement);
+            assert_eq!(right.displacement - left.displacement, 0.5);
+            assert_eq!(left.screen_depth, 2.0);
+            assert_eq!(right.screen_depth, 2.0);
+        }
+    }
+
+    #[test]
+    fn stereo_tilt() {
+        let clip_planes = ClipPlanes {
+            near: 0.1,
+            far: 100.0,
+        };
+        let projection = Projection::perspective(FRAC_PI_4, AspectRatio::TopScreen, clip_planes);
+        let center: Matrix4 = projection.clone().into();
+
+        let (left_eye, right_eye) = StereoDisplacement::new(0.5, 2.0);
+        let (left, right) = projection.clone().stereo_matrices(left_eye, right_eye);
+        assert_abs_diff_ne!(left, right);
+        assert_abs_diff_ne!(left, center);
+
+        // The eyes are displaced symmetrically around the center
+        assert_abs_diff_eq!(&left - &center, &center - &right);
+
+        // Tilt is applied by default, like non-stereo projections
+        let (rotated_left, rotated_right) = projection
+            .clone()
+            .screen(ScreenOrientation::Rotated)
+            .stereo_matrices(left_eye, right_eye);
+        assert_abs_diff_eq!(left, rotated_left);
+        assert_abs_diff_eq!(right, rotated_right);
+
+        // No displacement is the same as a regular perspective projection
+        let (left_eye, right_eye) = StereoDisplacement::new(0.0, 2.0);
+        let (left, right) = projection.stereo_matrices(left_eye, right_eye);
+        assert_abs_diff_eq!(left, center);
+        assert_abs_diff_eq!(right, center);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be less than far clip plane")]
+    fn perspective_near_equals_far() {
+        let clip_planes = ClipPlanes {
+            near: 1.0,
+            far: 1.0,
+        };
+        Projection::perspective(FRAC_PI_4, AspectRatio::TopScreen, clip_planes);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be less than far clip plane")]
+    fn perspective_near_greater_than_far() {
+        let clip_planes = ClipPlanes {
+            near: 100.0,
+            far: 0.1,
+        };
+        Projection::perspective(FRAC_PI_4, AspectRatio::TopScreen, clip_planes);
+    }
+
+    #[test]
+    #[should_panic(expected = "near clip plane must be positive")]
+    fn perspective_negative_near() {
+        let clip_planes = ClipPlanes {
+            near: -0.1,
+            far: 100.0,
+        };
+        Projection::perspective(FRAC_PI_4, AspectRatio::TopScreen, clip_planes);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be less than far clip plane")]
+    fn orthographic_near_equals_far() {
+        let clip_planes = ClipPlanes {
+            near: 0.0,
+            far: 0.0,
+        };
+        Projection::orthographic(0.0..400.0, 0.0..240.0, clip_planes);
+    }
+
+    #[test]
+    fn aspect_ratio_from_dimensions() {
+        assert_abs_diff_eq!(
+            f32::from(AspectRatio::from_dimensions(400, 240)),
+            f32::from(AspectRatio::TopScreen),
+        );
+        assert_abs_diff_eq!(
+            f32::from(AspectRatio::from_dimensions(320, 240)),
+            f32::from(AspectRatio::BottomScreen),
+        );
+        assert_abs_diff_eq!(f32::from(AspectRatio::from_dimensions(100, 50)), 2.0);
+    }
+
+    #[test]
+    fn perspective_tilt() {
+        let clip_planes = ClipPlanes {
+            near: 0.1,
+            far: 100.0,
+        };
+
+        for coordinates in [
+            CoordinateOrientation::LeftHanded,
+            CoordinateOrientation::RightHanded,
+        ] {
+            let projection =
+                Projection::perspective(FRAC_PI_4, AspectRatio::TopScreen, clip_planes)
+                    .coordinates(coordinates);
+
+            let tilted = projection
+                .clone()
+                .screen(ScreenOrientation::Rotated)
+                .to_matrix();
+            assert_abs_diff_eq!(tilted, projection.to_matrix());
+
+            let mut expected = projection.screen(ScreenOrientation::None).to_matrix();
+            assert_abs_diff_ne!(tilted, expected);
+
+            // The 3DS screens are rotated 90° clockwise relative to the framebuffer
+            expected.rotate_z(-FRAC_PI_2);
+            assert_abs_diff_eq!(tilted, expected);
+        }
+    }
+
+    #[test]
+    fn orthographic_tilt() {
+        let clip_planes = ClipPlanes {
+            near: 0.0,
+            far: 100.0,
+        };
+
+        for coordinates in [
+            CoordinateOrientation::LeftHanded,
+            CoordinateOrientation::RightHanded,
+        ] {
+            let projection = Projection::orthographic(0.0..400.0, 0.0..240.0, clip_planes)
+                .coordinates(coordinates);
+
+            let tilted: Matrix4 = projection.clone().into();
+            let mut expected: Matrix4 = projection.screen(ScreenOrientation::None).into();
+            // The 3DS screens are rotated 90° clockwise relative to the framebuffer
+            expected.rotate_z(-FRAC_PI_2);
+
+            assert_abs_diff_eq!(tilted, expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let clip_planes = ClipPlanes {
+            near: 0.01,
+            far: 100.0,
+        };
+        let (left, _) = StereoDisplacement::new(0.5, 2.0);
+        let projection = Projection::perspective_degrees(40.0, AspectRatio::TopScreen, clip_planes)
+            .coordinates(CoordinateOrientation::LeftHanded)
+            .stereo(left);
+
+        let json = serde_json::to_string(&projection).unwrap();
+        let deserialized: Projection<Perspective> = serde_json::from_str(&json).unwrap();
+        assert_abs_diff_eq!(Matrix4::from(deserialized), Matrix4::from(projection));
+
+        let projection = Projection::orthographic(0.0..400.0, 0.0..240.0, clip_planes)
+            .screen(ScreenOrientation::None);
+
+        let json = serde_json::to_string(&projection).unwrap();
+        let deserialized: Projection<Orthographic> = serde_json::from_str(&json).unwrap();
+        assert_abs_diff_eq!(Matrix4::from(deserialized), Matrix4::from(projection));
+
+        let aspect = AspectRatio::Other(1.5);
+        let json = serde_json::to_string(&aspect).unwrap();
+        let deserialized: AspectRatio = serde_json::from_str(&json).unwrap();
+        assert_eq!(f32::from(deserialized), 1.5);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_rejects_invalid() {
+        // Serializing doesn't validate, so bypass the constructors to produce
+        // the same JSON as a hand-written invalid config would have
+        let inverted = ClipPlanes {
+            near: 100.0,
+            far: 0.01,
+        };
+        let json = serde_json::to_string(&inverted).unwrap();
+        assert!(serde_json::from_str::<ClipPlanes>(&json).is_err());
+
+        let orthographic = Projection::new(Orthographic {
+            clip_planes_x: 0.0..400.0,
+            clip_planes_y: 0.0..240.0,
+            clip_planes_z: inverted,
+        });
+        let json = serde_json::to_string(&orthographic).unwrap();
+        assert!(serde_json::from_str::<Projection<Orthographic>>(&json).is_err());
+
+        let perspective = Projection::new(Perspective {
+            vertical_fov_radians: 1.0,
+            aspect_ratio: AspectRatio::TopScreen,
+            clip_planes: ClipPlanes {
+                near: 0.0,
+                far: 100.0,
+            },
+            stereo: None,
+        });
+        let json = serde_json::to_string(&perspective).unwrap();
+        let err = serde_json::from_str::<Projection<Perspective>>(&json).unwrap_err();
+        assert!(err.to_string().contains("must be positive"), "{err}");
+    }
+}
@@ -0,0 +1,198 @@
+//! [`serde`] support for the math types.
+//!
+//! Everything is serialized in its logical order rather than the internal
+//! WZYX layout used by `citro3d`: vectors as `[x, y, z, w]`, quaternions as
+//! `[i, j, k, r]`, and matrices as an array of rows, each in column order.
+//!
+//! The [projection](super::Projection) configuration types derive their
+//! implementations, since they don't have any special storage layout. They are
+//! validated the same way as by their constructors when deserialized.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+
+use super::{FQuat, FVec, IVec, Matrix};
+
+#[doc(cfg(feature = "serde"))]
+impl<const N: usize> Serialize for FVec<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Array(self.as_array()).serialize(serializer)
+    }
+}
+
+#[doc(cfg(feature = "serde"))]
+impl<'de, const N: usize> Deserialize<'de> for FVec<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Array::<f32, N>(components) = Array::deserialize(deserializer)?;
+
+        let mut out = Self::zero();
+        for (i, component) in components.into_iter().enumerate() {
+            out[i] = component;
+        }
+        Ok(out)
+    }
+}
+
+#[doc(cfg(feature = "serde"))]
+impl Serialize for FQuat {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut ijkr = unsafe { self.0.c };
+        // Components are stored in RKJI order
+        ijkr.reverse();
+        Array(ijkr).serialize(serializer)
+    }
+}
+
+#[doc(cfg(feature = "serde"))]
+impl<'de> Deserialize<'de> for FQuat {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Array([i, j, k, r]) = Array::deserialize(deserializer)?;
+        Ok(Self::new(i, j, k, r))
+    }
+}
+
+#[doc(cfg(feature = "serde"))]
+impl Serialize for IVec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Array([self.x(), self.y(), self.z(), self.w()]).serialize(serializer)
+    }
+}
+
+#[doc(cfg(feature = "serde"))]
+impl<'de> Deserialize<'de> for IVec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Array([x, y, z, w]) = Array::deserialize(deserializer)?;
+        Ok(Self::new(x, y, z, w))
+    }
+}
+
+#[doc(cfg(feature = "serde"))]
+impl<const M: usize, const N: usize> Serialize for Matrix<M, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Array(self.to_rows().map(Array)).serialize(serializer)
+    }
+}
+
+#[doc(cfg(feature = "serde"))]
+impl<'de, const M: usize, const N: usize> Deserialize<'de> for Matrix<M, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Array::<Array<f32, N>, M>(rows) = Array::deserialize(deserializer)?;
+        Ok(Self::from_rows(rows.map(|Array(row)| row)))
+    }
+}
+
+/// `serde` only implements its traits for arrays of specific sizes rather than
+/// any `N`, so this wrapper (de)serializes arrays as tuples the same way.
+struct Array<T, const N: usize>([T; N]);
+
+impl<T: Serialize, const N: usize> Serialize for Array<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for element in &self.0 {
+            tuple.serialize_element(element)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Array<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+            type Value = Array<T, N>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "an array of length {N}")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut elements = Vec::with_capacity(N);
+                for i in 0..N {
+                    let element = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                    elements.push(element);
+                }
+
+                // UNWRAP: we just pushed exactly N elements
+                Ok(Array(elements.try_into().ok().unwrap()))
+            }
+        }
+
+        deserializer.deserialize_tuple(N, ArrayVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{FVec3, FVec4, Matrix3, Matrix4};
+    use super::*;
+
+    #[test]
+    fn fvec() {
+        let v = FVec4::new(1.0, 2.0, 3.0, 4.0);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1.0,2.0,3.0,4.0]");
+        assert_eq!(serde_json::from_str::<FVec4>(&json).unwrap(), v);
+
+        let v = FVec3::new(-1.5, 0.0, 2.25);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[-1.5,0.0,2.25]");
+        assert_eq!(serde_json::from_str::<FVec3>(&json).unwrap(), v);
+
+        assert!(serde_json::from_str::<FVec3>("[1.0,2.0]").is_err());
+        assert!(serde_json::from_str::<FVec3>("[1.0,2.0,3.0,4.0]").is_err());
+    }
+
+    #[test]
+    fn fquat() {
+        let q = FQuat::new(1.0, 2.0, 3.0, 4.0);
+        let json = serde_json::to_string(&q).unwrap();
+        assert_eq!(json, "[1.0,2.0,3.0,4.0]");
+        assert_eq!(serde_json::from_str::<FQuat>(&json).unwrap(), q);
+    }
+
+    #[test]
+    fn ivec() {
+        let v = IVec::new(1, 2, 3, 255);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1,2,3,255]");
+        assert_eq!(serde_json::from_str::<IVec>(&json).unwrap(), v);
+    }
+
+    #[test]
+    fn matrix() {
+        let mut m = Matrix4::identity();
+        m.translate(1.0, 2.0, 3.0);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(
+            json,
+            "[[1.0,0.0,0.0,1.0],[0.0,1.0,0.0,2.0],[0.0,0.0,1.0,3.0],[0.0,0.0,0.0,1.0]]"
+        );
+        assert_eq!(serde_json::from_str::<Matrix4>(&json).unwrap(), m);
+
+        let m = Matrix3::from_rows([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, "[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]");
+        assert_eq!(serde_json::from_str::<Matrix3>(&json).unwrap(), m);
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut m = Matrix4::identity();
+        m.rotate(FVec3::new(1.0, -2.0, 0.5), 1.25);
+        m.scale(0.1, 2.0, 3.3);
+        m.translate(-4.0, 5.5, 1.0 / 3.0);
+
+        let json = serde_json::to_string(&m).unwrap();
+        approx::assert_relative_eq!(serde_json::from_str::<Matrix4>(&json).unwrap(), m);
+
+        let q = FQuat::from_axis_angle(FVec3::new(0.0, 1.0, 1.0).normalize(), 0.3);
+        let json = serde_json::to_string(&q).unwrap();
+        approx::assert_relative_eq!(serde_json::from_str::<FQuat>(&json).unwrap(), q);
+    }
+}